@@ -0,0 +1,84 @@
+//! Hash the sources of every day, so the answer cache of the runner is tied to the code
+//! that computed the answers.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 64 bit FNV-1a, the same as `cache::fnv1a` which the build script cannot use.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Every file under `dir`, sorted so the hash does not depend on the directory order.
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(self::files(&path));
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Hash of the manifest and sources of the crate in `crate_dir`, on top of `hash`.
+fn crate_hash(hash: u64, crate_dir: &Path) -> u64 {
+    let manifest = crate_dir.join("Cargo.toml");
+    let src = crate_dir.join("src");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    std::iter::once(manifest)
+        .chain(files(&src))
+        .fold(hash, |hash, path| {
+            let relative = path.strip_prefix(crate_dir).unwrap_or(&path);
+            let hash = fnv1a(hash, relative.to_string_lossy().as_bytes());
+
+            fnv1a(hash, &fs::read(&path).unwrap_or_default())
+        })
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let workspace_dir = manifest_dir.parent().unwrap();
+
+    // `new-day` adds the day to the workspace members, so a new day is hashed as well
+    println!(
+        "cargo:rerun-if-changed={}",
+        workspace_dir.join("Cargo.toml").display()
+    );
+
+    // Days build on `common`, a change there may change any answer
+    let common_hash = crate_hash(0xcbf2_9ce4_8422_2325, &workspace_dir.join("common"));
+    let mut days: Vec<(u32, u64)> = fs::read_dir(workspace_dir)
+        .unwrap()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let day = name.strip_prefix("day-")?.parse().ok()?;
+
+            Some((day, crate_hash(common_hash, &entry.path())))
+        })
+        .collect();
+    days.sort();
+
+    let entries: String = days
+        .iter()
+        .map(|(day, hash)| format!("    ({}, 0x{:016x}),\n", day, hash))
+        .collect();
+    let code = format!("&[\n{}]", entries);
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("source_hashes.rs"), code).unwrap();
+}
@@ -0,0 +1,118 @@
+//! `--alloc` mode - a global allocator counting the heap use of each part.
+
+use common::MemoryStats;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Off until [`enable`], so runs without `--alloc` only pay for one relaxed load.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    allocated: u64,
+    /// Bytes allocated minus bytes freed, negative when freeing older allocations
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Each part is solved on a single thread, so counting per thread keeps the parts
+    // solved side by side apart
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            allocated: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // The counters may already be gone while the thread exits
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+
+        if allocated > 0 {
+            current.allocations += 1;
+            current.allocated += allocated as u64;
+        }
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+
+        counters.set(current);
+    });
+}
+
+/// The system allocator, counting what the current thread allocates once enabled.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            record(0, layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Start counting allocations, for the rest of the run.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Run `f` and return its result with the heap use of the current thread meanwhile,
+/// `None` unless counting is enabled.
+pub fn measured<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    // The peak is only of interest from here on
+    let before = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.peak = current.live;
+        counters.set(current);
+        current
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let stats = MemoryStats {
+        allocations: after.allocations - before.allocations,
+        allocated: after.allocated - before.allocated,
+        peak: (after.peak - before.live) as u64,
+    };
+
+    (result, Some(stats))
+}
@@ -0,0 +1,233 @@
+//! `--bench N` mode - time parse, part one and part two of each day separately.
+
+use crate::{alloc, day_config, days, parse_configured, select_implementation};
+use common::{
+    format_bytes, format_duration, read_input, timed, AnySolution, Config, InputSource,
+    MemoryStats, Stats, DEFAULT_IMPLEMENTATION,
+};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const PHASES: [&str; 3] = ["parse", "part one", "part two"];
+
+/// One line of the machine readable report, durations in nanoseconds.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    phase: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    implementation: Option<&'static str>,
+    samples: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    memory: Option<MemoryStats>,
+}
+
+impl Record {
+    fn new(day: u32, phase: &Phase, stats: &Stats) -> Self {
+        Record {
+            day,
+            phase: PHASES[phase.part],
+            implementation: phase.implementation,
+            samples: stats.samples,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+            memory: phase.memory,
+        }
+    }
+}
+
+/// Parsing (part 0) or one implementation of a part, with its samples.
+struct Phase {
+    part: usize,
+    implementation: Option<&'static str>,
+    samples: Vec<Duration>,
+    /// Heap use of the first repetition, with `--alloc`
+    memory: Option<MemoryStats>,
+}
+
+impl Phase {
+    /// Name in the table, with the implementation when the part has several.
+    fn label(&self, solution: &dyn AnySolution) -> String {
+        match self.implementation {
+            Some(name)
+                if name != DEFAULT_IMPLEMENTATION
+                    || solution.implementations(self.part).len() > 1 =>
+            {
+                format!("{} ({})", PHASES[self.part], name)
+            }
+            _ => PHASES[self.part].to_string(),
+        }
+    }
+}
+
+/// Samples of parsing and of the implementation called `implementation` of each part,
+/// every implementation side by side when omitted. Applying `config` is part of parsing.
+fn bench_day(
+    solution: &dyn AnySolution,
+    file_content: &str,
+    config: &Config,
+    parts: &[usize],
+    implementation: Option<&str>,
+    repetitions: usize,
+) -> Result<Vec<Phase>, String> {
+    let mut phases = vec![Phase {
+        part: 0,
+        implementation: None,
+        samples: Vec::new(),
+        memory: None,
+    }];
+
+    for &part in parts {
+        let names = match implementation {
+            Some(name) => vec![select_implementation(solution, part, Some(name))?],
+            None => solution.implementations(part),
+        };
+
+        phases.extend(names.into_iter().map(|name| Phase {
+            part,
+            implementation: Some(name),
+            samples: Vec::new(),
+            memory: None,
+        }));
+    }
+
+    for _ in 0..repetitions {
+        let ((input, elapsed), memory) =
+            alloc::measured(|| timed(|| parse_configured(solution, file_content, config)));
+        let input = input?;
+        phases[0].samples.push(elapsed);
+        phases[0].memory = phases[0].memory.or(memory);
+
+        for phase in &mut phases[1..] {
+            let name = phase.implementation.unwrap_or(DEFAULT_IMPLEMENTATION);
+            let ((_, elapsed), memory) =
+                alloc::measured(|| timed(|| solution.solve_with(input.as_ref(), phase.part, name)));
+            phase.samples.push(elapsed);
+            phase.memory = phase.memory.or(memory);
+        }
+    }
+
+    Ok(phases)
+}
+
+/// The memory columns are only there when allocations are counted.
+fn print_header(memory: bool) {
+    print!(
+        "{:>3}  {:<18}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean", "Stddev"
+    );
+    if memory {
+        print!(
+            "  {:>11}  {:>11}  {:>11}",
+            "Allocations", "Allocated", "Peak"
+        );
+    }
+    println!();
+}
+
+fn print_record(day: u32, phase: &str, stats: &Stats, memory: Option<&MemoryStats>) {
+    print!(
+        "{:>3}  {:<18}  {:>10}  {:>10}  {:>10}  {:>10}",
+        day,
+        phase,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.stddev)
+    );
+    if let Some(memory) = memory {
+        print!(
+            "  {:>11}  {:>11}  {:>11}",
+            memory.allocations,
+            format_bytes(memory.allocated),
+            format_bytes(memory.peak)
+        );
+    }
+    println!();
+}
+
+/// Benchmark `parts` of every selected day `repetitions` times on `source`.
+///
+/// Parts with several implementations get one row each, or only the row of
+/// `implementation` when given. Each day is run with its `config.toml` and `settings`
+/// (`key=value`) over it. Prints a summary table and, when `report` is given,
+/// writes every record to it as JSON. With `--alloc`, the heap use of the first
+/// repetition of each phase is reported as well. Returns `false` when a day could not be benchmarked.
+pub fn bench(
+    selected: &[&dyn AnySolution],
+    source: &InputSource,
+    parts: &[usize],
+    repetitions: usize,
+    implementation: Option<&str>,
+    settings: &[String],
+    report: Option<&Path>,
+) -> bool {
+    let mut success = true;
+    let mut records = Vec::new();
+    let mut total_median = Duration::ZERO;
+
+    print_header(alloc::is_enabled());
+
+    for &solution in selected {
+        let day = solution.day();
+        let phases = read_input(source, &days::input_dir(day), false)
+            .map_err(|error| error.to_string())
+            .and_then(|file_content| {
+                let config = day_config(day, settings)?;
+                bench_day(
+                    solution,
+                    &file_content,
+                    &config,
+                    parts,
+                    implementation,
+                    repetitions,
+                )
+            });
+
+        let phases = match phases {
+            Ok(phases) => phases,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                success = false;
+                continue;
+            }
+        };
+
+        for (index, phase) in phases.iter().enumerate() {
+            let stats = Stats::from_samples(&phase.samples);
+            print_record(day, &phase.label(solution), &stats, phase.memory.as_ref());
+
+            // Only the first implementation of each part counts, alternatives would be timed twice
+            if index == 0 || phases[index - 1].part != phase.part {
+                total_median += stats.median;
+            }
+            records.push(Record::new(day, phase, &stats));
+        }
+    }
+
+    println!();
+    println!(
+        "Total of medians: {} ({} repetitions)",
+        format_duration(total_median),
+        repetitions
+    );
+
+    if let Some(report) = report {
+        let json = serde_json::to_string_pretty(&records).expect("Report should serialize");
+
+        if let Err(error) = fs::write(report, json + "\n") {
+            eprintln!("Could not write report to {:?}: {}", report, error);
+            success = false;
+        }
+    }
+
+    success
+}
@@ -0,0 +1,146 @@
+//! On-disk cache of answers, so days whose input did not change are not solved again.
+
+use crate::days;
+use common::{Answer, AnySolution, Config};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the cache lives, next to the builds it goes with.
+pub fn cache_path() -> PathBuf {
+    days::workspace_dir().join("target").join("aoc-cache.json")
+}
+
+/// 64 bit FNV-1a hash, stable across runs and platforms unlike the std hashers.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Answers by day, part, implementation, source hash, settings hash and input hash.
+///
+/// The source hash changes with any edit of the day or of `common`, so answers computed
+/// by other code are never reused.
+#[derive(Debug, Default)]
+pub struct AnswerCache {
+    answers: BTreeMap<String, Answer>,
+    modified: bool,
+}
+
+impl AnswerCache {
+    /// Cache stored at `path`, empty when there is none or it cannot be read.
+    pub fn load(path: &Path) -> AnswerCache {
+        let answers = fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+
+        AnswerCache {
+            answers,
+            modified: false,
+        }
+    }
+
+    /// Write the cache to `path` if answers were added since it was loaded.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if !self.modified {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.answers).expect("Answers should serialize");
+        fs::write(path, json + "\n")
+    }
+
+    /// Key of an answer, `None` when the sources of the day are unknown to the runner: its
+    /// answers could not be told apart from those of other code, so they are never cached.
+    pub fn key(
+        solution: &dyn AnySolution,
+        part: usize,
+        implementation: &str,
+        config: &Config,
+        file_content: &str,
+    ) -> Option<String> {
+        let source_hash = days::source_hash(solution.day())?;
+
+        Some(format!(
+            "day-{}/part-{}/{}/{:016x}/{:016x}/{:016x}",
+            solution.day(),
+            part,
+            implementation,
+            source_hash,
+            fnv1a(config.to_string().as_bytes()),
+            fnv1a(file_content.as_bytes())
+        ))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Answer> {
+        self.answers.get(key)
+    }
+
+    pub fn insert(&mut self, key: String, answer: Answer) {
+        self.answers.insert(key, answer);
+        self.modified = true;
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_fnv1a() {
+        // Reference values of the FNV specification
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn nominal_answer_cache() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-cache-{}", std::process::id()))
+            .join("aoc-cache.json");
+        let days = days::all();
+        let config = Config::default();
+        let key =
+            AnswerCache::key(days[0].as_ref(), 1, "default", &config, "1000\n2000\n").unwrap();
+
+        assert_eq!(
+            key,
+            format!(
+                "day-1/part-1/default/{:016x}/cbf29ce484222325/6267d714dce49b46",
+                days::source_hash(1).unwrap()
+            )
+        );
+        assert_ne!(days::source_hash(1), days::source_hash(2));
+        assert_eq!(days::source_hash(99), None);
+        assert_ne!(
+            Some(key.clone()),
+            AnswerCache::key(days[0].as_ref(), 1, "default", &config, "1000\n2001\n")
+        );
+
+        let mut other = Config::default();
+        other.set("rounds=30").unwrap();
+        assert_ne!(
+            Some(key.clone()),
+            AnswerCache::key(days[0].as_ref(), 1, "default", &other, "1000\n2000\n")
+        );
+
+        let mut cache = AnswerCache::load(&path);
+        assert_eq!(cache.get(&key), None);
+        cache.insert(key.clone(), Answer::from(3000));
+        cache.save(&path).unwrap();
+
+        let cache = AnswerCache::load(&path);
+        assert_eq!(cache.get(&key), Some(&Answer::from(3000)));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,567 @@
+//! Client of the puzzle website - download inputs and submit answers.
+
+use crate::days;
+use common::{Answer, InputSource};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub static CONFIG_FILE: &str = "aoc.toml";
+
+const USER_AGENT: &str = "github.com/Andrei-Basarab/aoc-2022 runner";
+
+/// Where the time of the last request is kept, so the throttle also holds across runs.
+pub fn throttle_path() -> PathBuf {
+    days::workspace_dir()
+        .join("target")
+        .join("aoc-last-request")
+}
+
+/// The `[client]` table of `aoc.toml`, at the root of the workspace.
+///
+/// ```toml
+/// [client]
+/// base_url = "https://adventofcode.com"
+/// session = "53616c746564..."
+/// year = 2022
+/// throttle_ms = 3000
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub year: u32,
+    /// Minimum delay between two requests, in milliseconds
+    pub throttle_ms: u64,
+}
+
+impl Default for ClientConfig {
+    fn default() -> ClientConfig {
+        ClientConfig {
+            base_url: "https://adventofcode.com".to_string(),
+            session: None,
+            year: 2022,
+            throttle_ms: 3000,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    client: ClientConfig,
+}
+
+impl ClientConfig {
+    /// Load `aoc.toml` from `workspace`, the defaults when there is none. `AOC_SESSION`
+    /// overrides its session.
+    pub fn load(workspace: &Path) -> Result<Self, String> {
+        let file_path = workspace.join(CONFIG_FILE);
+
+        let mut config = match fs::read_to_string(&file_path) {
+            Ok(file_content) => {
+                toml::from_str::<ConfigFile>(&file_content)
+                    .map_err(|error| {
+                        format!("Could not load {:?}: {}", file_path, error.message())
+                    })?
+                    .client
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => ClientConfig::default(),
+            Err(error) => return Err(format!("Could not read {:?}: {}", file_path, error)),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FetchOutcome {
+    /// The input was already there, nothing was requested
+    Cached,
+    /// Size of the downloaded input, in bytes
+    Downloaded(usize),
+}
+
+/// Sends requests one at a time, never faster than the configured throttle.
+pub struct Client {
+    config: ClientConfig,
+    agent: ureq::Agent,
+    last_request: Option<SystemTime>,
+    /// File keeping `last_request` between runs, see [`Client::with_throttle_file`]
+    throttle_path: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Self {
+        Client {
+            config,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+            last_request: None,
+            throttle_path: None,
+        }
+    }
+
+    /// Keep the time of the last request in `path`, so the throttle also waits for the
+    /// requests of previous runs.
+    pub fn with_throttle_file(mut self, path: PathBuf) -> Self {
+        self.last_request = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        self.throttle_path = Some(path);
+        self
+    }
+
+    fn day_url(&self, day: u32, page: &str) -> String {
+        format!(
+            "{}/{}/day/{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            self.config.year,
+            day,
+            page
+        )
+    }
+
+    /// Send `request` with the session cookie, once the throttle delay is over.
+    fn send(
+        &mut self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<Result<ureq::Response, ureq::Error>, String> {
+        let session = self.config.session.clone().ok_or_else(|| {
+            format!(
+                "No session token, set `session` in the [client] table of {} or AOC_SESSION",
+                CONFIG_FILE
+            )
+        })?;
+
+        if let Some(last_request) = self.last_request {
+            // A request from the future, after the clock went back, waits the full delay
+            let elapsed = last_request.elapsed().unwrap_or_default();
+            let wait = Duration::from_millis(self.config.throttle_ms).saturating_sub(elapsed);
+            thread::sleep(wait);
+        }
+
+        let request = request
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT);
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+
+        if let Some(path) = &self.throttle_path {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            let written = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, millis.to_string()));
+            if let Err(error) = written {
+                eprintln!(
+                    "Could not write the time of the last request to {:?}: {}",
+                    path, error
+                );
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Download the input of `day` to `input_dir/input.txt`, unless it is already there.
+    pub fn fetch(&mut self, day: u32, input_dir: &Path) -> Result<FetchOutcome, String> {
+        let file_path = InputSource::Puzzle.path(input_dir).unwrap();
+
+        // An empty file is only a placeholder, e.g. from `new-day`
+        if fs::metadata(&file_path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(FetchOutcome::Cached);
+        }
+
+        let url = self.day_url(day, "input");
+        let response = self.send(self.agent.get(&url), None)?;
+
+        let file_content = match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| format!("Could not read {}: {}", url, error))?,
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(format!("{} answered with status {}", url, status))
+            }
+            Err(error) => return Err(format!("Could not download {}: {}", url, error)),
+        };
+
+        // Written aside first, so an interrupted download is never taken for a cached one
+        let part_path = file_path.with_extension("txt.part");
+        fs::create_dir_all(input_dir)
+            .and_then(|_| fs::write(&part_path, &file_content))
+            .and_then(|_| fs::rename(&part_path, &file_path))
+            .map_err(|error| format!("Could not write {:?}: {}", file_path, error))?;
+
+        Ok(FetchOutcome::Downloaded(file_content.len()))
+    }
+
+    /// Post `answer` to `part` of `day` and tell what the website made of it.
+    pub fn submit(
+        &mut self,
+        day: u32,
+        part: usize,
+        answer: &Answer,
+    ) -> Result<SubmitOutcome, String> {
+        let url = self.day_url(day, "answer");
+        let level = part.to_string();
+        let answer = answer.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+
+        match self.send(self.agent.post(&url), Some(&form))? {
+            Ok(response) => response
+                .into_string()
+                .map(|html| SubmitOutcome::from_html(&html))
+                .map_err(|error| format!("Could not read {}: {}", url, error)),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(format!("{} answered with status {}", url, status))
+            }
+            Err(error) => Err(format!("Could not submit to {}: {}", url, error)),
+        }
+    }
+}
+
+/// What the puzzle website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling in which direction
+    Wrong,
+    /// Answered too recently, holds the time left to wait
+    RateLimited(Duration),
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+    /// Text of a page that could not be understood
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Understand the page returned after posting an answer, from the text of its `<article>`.
+    pub fn from_html(html: &str) -> SubmitOutcome {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("your answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmitOutcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            SubmitOutcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited(parse_wait(&text).unwrap_or_default())
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+}
+
+/// Text of the `<article>` of `html` (or all of it), without tags and extra whitespace.
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |index| start + index);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for char in html[start..end].chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Wait from "You have 1m 23s left to wait", as a duration.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+
+    for amount in text[start..end].split_whitespace() {
+        let (value, unit) = amount.split_at(amount.len() - 1);
+        let value: u64 = value.parse().ok()?;
+
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    /// Stand-in for the puzzle website, answering `count` requests with `respond`.
+    ///
+    /// Returns its base URL and the requests it received, headers and body.
+    pub(crate) fn serve(
+        count: usize,
+        respond: fn(&str) -> (&'static str, String),
+    ) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+
+                // Request line and headers, up to the blank line
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request += &line;
+                }
+
+                let content_length = request
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.trim().parse().unwrap());
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                request += &String::from_utf8(body).unwrap();
+
+                let (status, body) = respond(&request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn input_page(request: &str) -> (&'static str, String) {
+        if request.contains("session=valid") {
+            ("200 OK", "1000\n2000\n".to_string())
+        } else {
+            (
+                "400 Bad Request",
+                "Puzzle inputs differ by user.\n".to_string(),
+            )
+        }
+    }
+
+    pub(crate) fn answer_page(request: &str) -> (&'static str, String) {
+        let text = if request.ends_with("answer=24000") {
+            "That's the right answer! You are <em>one gold star</em> closer."
+        } else {
+            "That's not the right answer; your answer is too low."
+        };
+
+        (
+            "200 OK",
+            format!(
+                "<html><main><article><p>{}</p></article></main></html>",
+                text
+            ),
+        )
+    }
+
+    pub(crate) fn temp_input_dir(name: &str) -> PathBuf {
+        let input_dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        input_dir
+    }
+
+    pub(crate) fn config(base_url: String, session: &str, throttle_ms: u64) -> ClientConfig {
+        ClientConfig {
+            base_url,
+            session: Some(session.to_string()),
+            year: 2022,
+            throttle_ms,
+        }
+    }
+
+    #[test]
+    fn nominal_fetch_then_cached() {
+        let (base_url, requests) = serve(1, input_page);
+        let input_dir = temp_input_dir("nominal");
+        let mut client = Client::new(config(base_url, "valid", 0));
+
+        assert_eq!(
+            client.fetch(1, &input_dir),
+            Ok(FetchOutcome::Downloaded(10))
+        );
+        assert_eq!(client.fetch(1, &input_dir), Ok(FetchOutcome::Cached));
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=valid"));
+        assert!(requests.try_recv().is_err());
+        assert_eq!(
+            fs::read_to_string(input_dir.join("input.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn throttled_fetch() {
+        let (base_url, _requests) = serve(2, input_page);
+        let input_dirs = [temp_input_dir("throttle-1"), temp_input_dir("throttle-2")];
+        let mut client = Client::new(config(base_url, "valid", 300));
+
+        let start = Instant::now();
+        for (day, input_dir) in input_dirs.iter().enumerate() {
+            client.fetch(day as u32 + 1, input_dir).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        for input_dir in input_dirs {
+            fs::remove_dir_all(input_dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn throttled_across_runs() {
+        let (base_url, _requests) = serve(2, input_page);
+        let input_dirs = [temp_input_dir("run-1"), temp_input_dir("run-2")];
+        let throttle_path = temp_input_dir("last-request").join("last-request");
+
+        let start = Instant::now();
+        for (day, input_dir) in input_dirs.iter().enumerate() {
+            let mut client = Client::new(config(base_url.clone(), "valid", 300))
+                .with_throttle_file(throttle_path.clone());
+            client.fetch(day as u32 + 1, input_dir).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        for input_dir in input_dirs {
+            fs::remove_dir_all(input_dir).unwrap();
+        }
+        fs::remove_dir_all(throttle_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn unreadable_config() {
+        let workspace = temp_input_dir("config");
+        fs::create_dir_all(workspace.join(CONFIG_FILE)).unwrap();
+
+        let error = ClientConfig::load(&workspace).unwrap_err();
+        assert!(error.contains("Could not read"));
+        assert!(ClientConfig::load(&workspace.join("missing")).is_ok());
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+
+    #[test]
+    fn rejected_session() {
+        let (base_url, _requests) = serve(1, input_page);
+        let input_dir = temp_input_dir("rejected");
+        let mut client = Client::new(config(base_url, "expired", 0));
+
+        let error = client.fetch(3, &input_dir).unwrap_err();
+        assert!(error.contains("status 400"));
+        assert!(!input_dir.join("input.txt").exists());
+    }
+
+    #[test]
+    fn nominal_submit() {
+        let (base_url, requests) = serve(2, answer_page);
+        let mut client = Client::new(config(base_url, "valid", 0));
+
+        assert_eq!(
+            client.submit(1, 1, &Answer::from(24000)),
+            Ok(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            client.submit(1, 2, &Answer::from(3)),
+            Ok(SubmitOutcome::TooLow)
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.contains("session=valid"));
+        assert!(request.ends_with("\r\nlevel=1&answer=24000"));
+    }
+
+    #[test]
+    fn nominal_submit_outcome_from_html() {
+        let page = |text: &str| format!("<article><p>{}</p></article><p>Footer</p>", text);
+
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page("That's not the right answer. If you're stuck...")),
+            SubmitOutcome::Wrong
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                before trying again. You have 1m 23s left to wait."
+            )),
+            SubmitOutcome::RateLimited(Duration::from_secs(83))
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            SubmitOutcome::from_html("<p>Maintenance</p>"),
+            SubmitOutcome::Unknown("Maintenance".to_string())
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Registry of every solved day linked into the runner.
+
+use common::AnySolution;
+use std::path::{Path, PathBuf};
+
+pub fn all() -> Vec<Box<dyn AnySolution>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6),
+        Box::new(day_7::Day7),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11),
+        Box::new(day_12::Day12),
+    ]
+}
+
+/// Hashes of the sources of each day and of `common`, computed by the build script.
+const SOURCE_HASHES: &[(u32, u64)] = include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// Hash of the sources the runner was built with for `day`, changing with any edit of the
+/// day or of `common`. `None` for a day the build script did not see.
+pub fn source_hash(day: u32) -> Option<u64> {
+    SOURCE_HASHES
+        .iter()
+        .find(|(hash_day, _)| *hash_day == day)
+        .map(|(_, hash)| *hash)
+}
+
+/// Root of the repository, where all the `day-n` folders live.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate should live inside the workspace")
+}
+
+/// The `day-n` folder of a day, holding its crate and `config.toml`.
+pub fn day_dir(day: u32) -> PathBuf {
+    workspace_dir().join(format!("day-{}", day))
+}
+
+/// The `input` folder of a day, holding `input.txt` and the example files.
+pub fn input_dir(day: u32) -> PathBuf {
+    day_dir(day).join("input")
+}
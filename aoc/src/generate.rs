@@ -0,0 +1,59 @@
+//! `generate` subcommand - random valid inputs, to stress test a day beyond its puzzle input.
+
+use crate::days;
+use common::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seed used when none is given, different on every run.
+pub fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Input of `day` made of about `size` records, the same for the same `seed`.
+pub fn generate(day: u32, size: usize, seed: u64) -> Result<String, String> {
+    let days = days::all();
+    let solution = days
+        .iter()
+        .find(|solution| solution.day() == day)
+        .ok_or_else(|| format!("Day {} is not available", day))?;
+
+    solution
+        .generate_any(size, &mut Rng::new(seed))
+        .ok_or_else(|| format!("Day {} has no input generator", day))
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_generate_every_day() {
+        for solution in days::all() {
+            for (size, seed) in [(1, 0), (10, 1), (10, 2), (60, 3)] {
+                let file_content = generate(solution.day(), size, seed).unwrap();
+                let input = solution.parse_any(&file_content).unwrap_or_else(|error| {
+                    panic!("Day {}: {}", solution.day(), error.snippet(&file_content))
+                });
+
+                for part in [1, 2] {
+                    solution.solve_any(input.as_ref(), part);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nominal_generate_reproducible() {
+        assert_eq!(generate(9, 20, 7), generate(9, 20, 7));
+        assert_ne!(generate(9, 20, 7), generate(9, 20, 8));
+        assert_eq!(
+            generate(99, 20, 7),
+            Err("Day 99 is not available".to_string())
+        );
+    }
+}
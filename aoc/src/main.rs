@@ -0,0 +1,790 @@
+//! Advent of Code 2022 runner - solves any day from one binary.
+
+mod alloc;
+mod bench;
+mod cache;
+mod client;
+mod days;
+mod generate;
+mod scaffold;
+mod submit;
+mod verify;
+mod visualize;
+mod watch;
+
+use cache::AnswerCache;
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use common::export::Exporter;
+use common::viz::Player;
+use common::{
+    format_results, input_banner, read_input, timed, Answer, AnySolution, Config, InputSource,
+    OutputFormat, PartResult, DEFAULT_IMPLEMENTATION,
+};
+use rayon::prelude::*;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(self) -> Vec<usize> {
+        match self {
+            PartSelection::One => vec![1],
+            PartSelection::Two => vec![2],
+            PartSelection::Both => vec![1, 2],
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate the crate of a new day and register it with the runner
+    NewDay {
+        /// Number of the new day
+        day: u32,
+
+        /// Title of the puzzle, e.g. "Distress Signal"
+        #[arg(short, long, default_value = "TODO")]
+        title: String,
+    },
+    /// Download missing puzzle inputs, configured by the [client] table of `aoc.toml`
+    Fetch {
+        /// Day to download, every available day when omitted
+        #[arg(short, long)]
+        day: Option<u32>,
+    },
+    /// Submit an answer and record the verdict in `input/answers.toml`
+    Submit {
+        /// Day of the answer
+        #[arg(short, long)]
+        day: u32,
+
+        /// Part of the answer
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit, solved from `input/input.txt` when omitted
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Print a random valid input of a day, to solve with `--input`
+    Generate {
+        /// Day of the input
+        #[arg(short, long)]
+        day: u32,
+
+        /// Number of records (lines, groups, rows...) of the input
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed of the random generator, drawn from the clock and printed when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+}
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions runner")]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to solve
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Solve every available day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Part(s) of the puzzle to solve
+    #[arg(short, long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+
+    /// Read the input from this file instead, `-` for stdin
+    #[arg(short, long, conflicts_with_all = ["all", "example"])]
+    input: Option<InputSource>,
+
+    /// Solve `input/example-N.txt` instead of the puzzle input
+    #[arg(short, long, value_name = "N")]
+    example: Option<u32>,
+
+    /// Do not print where the input was read from
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Check the answers on every available input against `input/answers.toml`
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    verify: bool,
+
+    /// Time parse, part one and part two over N repetitions instead of printing answers
+    #[arg(long, value_name = "N", conflicts_with = "verify", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Also write the benchmark results to this file as JSON
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    bench_report: Option<PathBuf>,
+
+    /// Solve the parts with the implementation called NAME instead of the default one
+    #[arg(
+        long = "impl",
+        value_name = "NAME",
+        requires = "day",
+        conflicts_with = "verify"
+    )]
+    implementation: Option<String>,
+
+    /// Solve the parts with every implementation of the day and fail if they disagree
+    #[arg(long, conflicts_with_all = ["verify", "bench", "implementation"])]
+    cross_check: bool,
+
+    /// Override a setting of the day's `config.toml`, e.g. --set rounds_part_two=20000
+    #[arg(
+        long = "set",
+        value_name = "KEY=VALUE",
+        requires = "day",
+        conflicts_with = "verify"
+    )]
+    settings: Vec<String>,
+
+    /// Solve every part again instead of reusing the answers cached for the same input
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Count the allocations of each part: how many, the bytes allocated and the peak heap
+    #[arg(long, conflicts_with_all = ["verify", "visualize", "export"])]
+    alloc: bool,
+
+    /// Output format of the answers: text, json, csv or markdown
+    #[arg(short, long, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Solve days and their parts on N threads, 0 for one per CPU
+    #[arg(short, long, value_name = "N", default_value_t = 1, conflicts_with_all = ["verify", "bench"])]
+    jobs: usize,
+
+    /// Solve the day again whenever its inputs change, rebuilding when its sources change
+    #[arg(short, long, requires = "day", conflicts_with_all = ["verify", "bench", "visualize", "export"])]
+    watch: bool,
+
+    /// Play the simulation behind the day in the terminal: space pauses, n steps, q quits
+    #[arg(long, requires = "day", conflicts_with_all = ["verify", "bench"])]
+    visualize: bool,
+
+    /// Draw the simulation behind the day to FILE instead: a PNG of its end or a GIF of all of it
+    #[arg(long, value_name = "FILE", requires = "day", conflicts_with_all = ["verify", "bench", "visualize"])]
+    export: Option<PathBuf>,
+
+    /// Pixels per cell of the exported pictures
+    #[arg(long, value_name = "N", default_value_t = 4, requires = "export", value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+
+    /// Keep one frame out of N in exported GIFs, for long simulations
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "export", value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+
+    /// Frames per second of the visualization or of the exported GIF
+    #[arg(long, value_name = "N", default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..=1000))]
+    fps: u32,
+
+    /// Start the visualization paused, one frame per key press
+    #[arg(long, requires = "visualize")]
+    step: bool,
+
+    /// Draw the visualization without colours
+    #[arg(long, requires = "visualize")]
+    no_colour: bool,
+}
+
+impl Cli {
+    fn input_source(&self) -> InputSource {
+        match (&self.input, self.example) {
+            (Some(source), _) => source.clone(),
+            (None, Some(number)) => InputSource::Example(number),
+            (None, None) => InputSource::Puzzle,
+        }
+    }
+}
+
+/// The implementation of `part` called `name`, [`DEFAULT_IMPLEMENTATION`] when omitted.
+fn select_implementation(
+    solution: &dyn AnySolution,
+    part: usize,
+    name: Option<&str>,
+) -> Result<&'static str, String> {
+    let name = name.unwrap_or(DEFAULT_IMPLEMENTATION);
+    let implementations = solution.implementations(part);
+
+    implementations
+        .iter()
+        .find(|&&implementation| implementation == name)
+        .copied()
+        .ok_or_else(|| {
+            format!(
+                "Part {} has no implementation `{}`, only {}",
+                part,
+                name,
+                implementations.join(", ")
+            )
+        })
+}
+
+/// Solve `part` with the implementation selected by `--impl` and, with `--cross-check`,
+/// with every other one, failing on the first answer that differs.
+fn solve_part(
+    solution: &dyn AnySolution,
+    input: &dyn Any,
+    part: usize,
+    cli: &Cli,
+) -> Result<PartResult, String> {
+    let name = select_implementation(solution, part, cli.implementation.as_deref())?;
+    let ((answer, time), memory) =
+        alloc::measured(|| timed(|| solution.solve_with(input, part, name)));
+    let answer = answer.expect("Selected implementation should exist");
+
+    if cli.cross_check {
+        for other in solution.implementations(part) {
+            let other_answer = solution
+                .solve_with(input, part, other)
+                .expect("Listed implementation should exist");
+
+            if other_answer != answer {
+                return Err(format!(
+                    "Part {}: `{}` answered {} but `{}` answered {}",
+                    part, name, answer, other, other_answer
+                ));
+            }
+        }
+    }
+
+    Ok(PartResult {
+        memory,
+        ..part_result(solution, part, answer, time)
+    })
+}
+
+fn part_result(
+    solution: &dyn AnySolution,
+    part: usize,
+    answer: Answer,
+    time: Duration,
+) -> PartResult {
+    PartResult {
+        day: solution.day(),
+        name: solution.name().to_string(),
+        part,
+        question: solution.questions()[part - 1].to_string(),
+        answer,
+        time,
+        cached: false,
+        memory: None,
+    }
+}
+
+/// Settings of `day-N/config.toml` with `settings` (`key=value`) applied over them.
+fn day_config(day: u32, settings: &[String]) -> Result<Config, String> {
+    let mut config = Config::load(&days::day_dir(day)).map_err(|error| error.to_string())?;
+
+    for setting in settings {
+        config.set(setting).map_err(|error| error.to_string())?;
+    }
+
+    Ok(config)
+}
+
+/// Parse `file_content` and apply `config` to it, errors as printed to the user.
+fn parse_configured(
+    solution: &dyn AnySolution,
+    file_content: &str,
+    config: &Config,
+) -> Result<Box<dyn Any + Send + Sync>, String> {
+    let mut input = solution
+        .parse_any(file_content)
+        .map_err(|error| error.snippet(file_content))?;
+
+    solution
+        .configure_any(input.as_mut(), config)
+        .map_err(|error| error.to_string())?;
+
+    Ok(input)
+}
+
+/// Everything solving a day prints, captured so days solved side by side never interleave.
+#[derive(Debug, Default)]
+struct DayOutput {
+    stdout: String,
+    stderr: String,
+    results: Vec<PartResult>,
+    /// Results taken from the answer cache instead of solved
+    cache_hits: usize,
+}
+
+/// Solve the selected parts of a day, reusing the answers of `cache` and adding the new
+/// ones to it. Answers are never cached when cross-checking or counting allocations, every
+/// implementation must run.
+fn solve_day(
+    solution: &dyn AnySolution,
+    cli: &Cli,
+    cache: Option<&Mutex<AnswerCache>>,
+) -> DayOutput {
+    let input_dir = days::input_dir(solution.day());
+    let source = cli.input_source();
+    let mut output = DayOutput::default();
+
+    let file_content = match read_input(&source, &input_dir, false) {
+        Ok(file_content) => file_content,
+        Err(error) => {
+            output.stderr = format!("Day {}: {}\n", solution.day(), error);
+            return output;
+        }
+    };
+
+    let config = match day_config(solution.day(), &cli.settings) {
+        Ok(config) => config,
+        Err(error) => {
+            output.stderr = format!("Day {}: {}\n", solution.day(), error);
+            return output;
+        }
+    };
+
+    let cache = cache.filter(|_| !cli.cross_check && !cli.alloc);
+    let implementation = cli
+        .implementation
+        .as_deref()
+        .unwrap_or(DEFAULT_IMPLEMENTATION);
+    let key = |part| AnswerCache::key(solution, part, implementation, &config, &file_content);
+    let cached: Vec<(usize, Option<Answer>)> = cli
+        .part
+        .parts()
+        .into_iter()
+        .map(|part| {
+            let answer = cache
+                .zip(key(part))
+                .and_then(|(cache, key)| cache.lock().unwrap().get(&key).cloned());
+            (part, answer)
+        })
+        .collect();
+
+    // With every answer cached, the input does not even need to be parsed
+    let input = if cached.iter().all(|(_, answer)| answer.is_some()) {
+        None
+    } else {
+        match parse_configured(solution, &file_content, &config) {
+            Ok(input) => Some(input),
+            Err(error) => {
+                output.stderr = format!("Day {}: {}\n", solution.day(), error);
+                return output;
+            }
+        }
+    };
+
+    // Parts only share the parsed input, so they are solved side by side as well
+    let solved: Vec<Result<(PartResult, bool), String>> = cached
+        .into_par_iter()
+        .map(|(part, answer)| match answer {
+            Some(answer) => {
+                let result = PartResult {
+                    cached: true,
+                    ..part_result(solution, part, answer, Duration::ZERO)
+                };
+                Ok((result, true))
+            }
+            None => {
+                let input = input.as_ref().expect("Input should be parsed");
+                solve_part(solution, input.as_ref(), part, cli).map(|result| (result, false))
+            }
+        })
+        .collect();
+
+    for result in solved {
+        match result {
+            Ok((result, true)) => {
+                output.cache_hits += 1;
+                output.results.push(result);
+            }
+            Ok((result, false)) => {
+                if let Some((cache, key)) = cache.zip(key(result.part)) {
+                    cache.lock().unwrap().insert(key, result.answer.clone());
+                }
+                output.results.push(result);
+            }
+            Err(error) => output.stderr += &format!("Day {}: {}\n", solution.day(), error),
+        }
+    }
+
+    // Only the text output has room for the input banner
+    if cli.format == OutputFormat::Text {
+        if !cli.quiet {
+            output.stdout += &input_banner(&source, &input_dir);
+        }
+        output.stdout += &format_results(&output.results, cli.format);
+
+        if cli.cross_check {
+            for result in &output.results {
+                let implementations = solution.implementations(result.part);
+                if implementations.len() > 1 {
+                    output.stdout += &format!(
+                        "    Part {} cross-checked: {} agree\n\n",
+                        result.part,
+                        implementations.join(", ")
+                    );
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Solve `selected` on `cli.jobs` threads and hand each day to `report` in selection order,
+/// as soon as it and every day before it are solved.
+fn solve_days(
+    selected: &[&dyn AnySolution],
+    cli: &Cli,
+    cache: Option<&Mutex<AnswerCache>>,
+    mut report: impl FnMut(DayOutput),
+) {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(cli.jobs)
+                .build()
+                .expect("Thread pool should be created");
+
+            pool.install(|| {
+                selected.par_iter().enumerate().for_each_with(
+                    sender,
+                    |sender, (index, solution)| {
+                        // The receiver only goes away once every day is sent
+                        sender
+                            .send((index, solve_day(*solution, cli, cache)))
+                            .unwrap();
+                    },
+                );
+            });
+        });
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+
+        for (index, output) in receiver {
+            pending.insert(index, output);
+
+            while let Some(output) = pending.remove(&next) {
+                report(output);
+                next += 1;
+            }
+        }
+    });
+}
+
+fn fetch_days(day: Option<u32>) -> ExitCode {
+    let config = match client::ClientConfig::load(days::workspace_dir()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut client = client::Client::new(config).with_throttle_file(client::throttle_path());
+    let mut success = true;
+
+    let numbers: Vec<u32> = match day {
+        Some(number) => vec![number],
+        None => days::all().iter().map(|solution| solution.day()).collect(),
+    };
+
+    for number in numbers {
+        match client.fetch(number, &days::input_dir(number)) {
+            Ok(client::FetchOutcome::Cached) => println!("Day {}: already downloaded", number),
+            Ok(client::FetchOutcome::Downloaded(size)) => {
+                println!("Day {}: downloaded {} bytes", number, size)
+            }
+            Err(error) => {
+                eprintln!("Day {}: {}", number, error);
+                success = false;
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match &cli.command {
+        Some(Command::NewDay { day, title }) => {
+            return match scaffold::new_day(days::workspace_dir(), *day, title) {
+                Ok(()) => {
+                    println!("Created day-{} and registered it with the runner", day);
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Some(Command::Fetch { day }) => return fetch_days(*day),
+        Some(Command::Submit { day, part, answer }) => {
+            return match submit::submit(*day, *part as usize, answer.as_deref()) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Some(Command::Generate { day, size, seed }) => {
+            let seed = seed.unwrap_or_else(|| {
+                let seed = generate::clock_seed();
+                eprintln!("Day {}: seed {}", day, seed);
+                seed
+            });
+
+            return match generate::generate(*day, *size, seed) {
+                Ok(file_content) => {
+                    print!("{}", file_content);
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        None => {}
+    }
+
+    let days = days::all();
+
+    let selected: Vec<&dyn AnySolution> = match cli.day {
+        Some(number) => match days.iter().find(|solution| solution.day() == number) {
+            Some(solution) => vec![solution.as_ref()],
+            None => {
+                eprintln!("Day {} is not available", number);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days.iter().map(|solution| solution.as_ref()).collect(),
+    };
+
+    let mut success = true;
+
+    if cli.alloc {
+        alloc::enable();
+    }
+
+    if cli.verify {
+        success = verify::verify(&selected, &cli.part.parts());
+    } else if cli.visualize || cli.export.is_some() {
+        let day = selected[0].day();
+        let config = match day_config(day, &cli.settings) {
+            Ok(config) => config,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let player;
+        let exporter;
+        let output = if let Some(path) = &cli.export {
+            exporter = Exporter {
+                scale: cli.scale as usize,
+                fps: cli.fps,
+                every: cli.every as usize,
+                ..Exporter::default()
+            };
+            visualize::Output::File(&exporter, path)
+        } else {
+            player = Player {
+                // Keys can only be read from a terminal
+                interactive: io::stdin().is_terminal() && io::stdout().is_terminal(),
+                ..Player::for_terminal(cli.fps, !cli.no_colour, cli.step)
+            };
+            visualize::Output::Terminal(&player)
+        };
+
+        success = visualize::visualize(
+            selected[0],
+            &cli.input_source(),
+            &cli.part.parts(),
+            &config,
+            output,
+        );
+    } else if cli.watch {
+        success = watch::watch(selected[0], &cli);
+    } else if let Some(repetitions) = cli.bench {
+        success = bench::bench(
+            &selected,
+            &cli.input_source(),
+            &cli.part.parts(),
+            repetitions as usize,
+            cli.implementation.as_deref(),
+            &cli.settings,
+            cli.bench_report.as_deref(),
+        );
+    } else {
+        let mut results = Vec::new();
+        let mut cache_hits = 0;
+        let cache = (!cli.no_cache).then(|| Mutex::new(AnswerCache::load(&cache::cache_path())));
+
+        // Text is printed day by day, under the banner of its input
+        solve_days(&selected, &cli, cache.as_ref(), |output| {
+            print!("{}", output.stdout);
+            eprint!("{}", output.stderr);
+            success &= output.stderr.is_empty();
+            cache_hits += output.cache_hits;
+            results.extend(output.results);
+        });
+
+        if cli.format != OutputFormat::Text {
+            print!("{}", format_results(&results, cli.format));
+        } else if cache_hits > 0 {
+            println!(
+                "{} of {} answers reused from the cache, `--no-cache` solves them again",
+                cache_hits,
+                results.len()
+            );
+        }
+
+        if let Some(cache) = cache {
+            let path = cache::cache_path();
+            if let Err(error) = cache.into_inner().unwrap().save(&path) {
+                eprintln!("Could not write the answer cache to {:?}: {}", path, error);
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_solve_days_in_order() {
+        let cli = Cli::parse_from(["aoc", "--all", "--example", "1", "--jobs", "4"]);
+        let days = days::all();
+        let selected: Vec<&dyn AnySolution> = days.iter().map(|day| day.as_ref()).collect();
+        let mut reported = Vec::new();
+
+        solve_days(&selected, &cli, None, |output| {
+            assert_eq!(output.stderr, "");
+            assert!(output.stdout.contains("example-1.txt"));
+            reported.extend(
+                output
+                    .results
+                    .iter()
+                    .map(|result| (result.day, result.part)),
+            );
+        });
+
+        let expected: Vec<(u32, usize)> = selected
+            .iter()
+            .flat_map(|solution| [(solution.day(), 1), (solution.day(), 2)])
+            .collect();
+        assert_eq!(reported, expected);
+    }
+
+    #[test]
+    fn nominal_cross_check() {
+        let cli = Cli::parse_from(["aoc", "--day", "8", "--example", "1", "--cross-check"]);
+        let days = days::all();
+        let day = days.iter().find(|day| day.day() == 8).unwrap();
+        let output = solve_day(day.as_ref(), &cli, None);
+
+        assert_eq!(output.stderr, "");
+        assert_eq!(output.results.len(), 2);
+        assert!(output.stdout.contains("default, stack agree"));
+    }
+
+    #[test]
+    fn nominal_commented_config_defaults() {
+        for solution in days::all() {
+            let day_dir = days::workspace_dir().join(format!("day-{}", solution.day()));
+            let Ok(file_content) = std::fs::read_to_string(day_dir.join("config.toml")) else {
+                continue;
+            };
+
+            // The file changes nothing as committed, nor once every setting is uncommented
+            let config = Config::load(&day_dir).unwrap();
+            assert_eq!(config.to_string(), Config::default().to_string());
+
+            let uncommented: String = file_content
+                .lines()
+                .map(|line| match line.strip_prefix("# ") {
+                    Some(setting) if setting.contains(" = ") => setting,
+                    _ => line,
+                })
+                .map(|line| line.to_string() + "\n")
+                .collect();
+            let config = Config::parse(&uncommented).unwrap();
+            assert_ne!(config.to_string(), Config::default().to_string());
+
+            let example =
+                std::fs::read_to_string(days::input_dir(solution.day()).join("example-1.txt"))
+                    .unwrap();
+            let input = parse_configured(solution.as_ref(), &example, &config).unwrap();
+            let default_input =
+                parse_configured(solution.as_ref(), &example, &Config::default()).unwrap();
+
+            for part in 1..=2 {
+                assert_eq!(
+                    solution.solve_any(input.as_ref(), part),
+                    solution.solve_any(default_input.as_ref(), part),
+                    "Day {} part {}",
+                    solution.day(),
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_implementation() {
+        let cli = Cli::parse_from(["aoc", "--day", "3", "--example", "1", "--impl", "stack"]);
+        let days = days::all();
+        let day = days.iter().find(|day| day.day() == 3).unwrap();
+        let output = solve_day(day.as_ref(), &cli, None);
+
+        assert!(output.results.is_empty());
+        assert!(output
+            .stderr
+            .contains("Day 3: Part 1 has no implementation `stack`, only default"));
+    }
+}
@@ -0,0 +1,251 @@
+//! `new-day` subcommand - generate a day crate and register it with the workspace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.toml.tmpl");
+
+/// Fill in `template`. `{{name}}` is the quoted and escaped name of the day, ready to be
+/// used as a Rust string literal.
+fn render(template: &str, day: u32, title: &str) -> String {
+    let name = format!("--- Day {}: {} ---", day, title);
+
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{name}}", &format!("{:?}", name))
+        .replace("{{title}}", title)
+}
+
+/// Day a line of a registry file is about, from its `day-N` or `day_N`.
+fn line_day(line: &str) -> Option<u32> {
+    let index = line.find("day-").or_else(|| line.find("day_"))?;
+    let digits: String = line[index + 4..]
+        .chars()
+        .take_while(|char| char.is_ascii_digit())
+        .collect();
+
+    digits.parse().ok()
+}
+
+/// Insert `new_line` in `text`, right after the last line registering an earlier day.
+///
+/// Returns `None` when no such line exists, so the caller knows the file layout changed.
+fn insert_day_line(text: &str, day: u32, new_line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+
+    if lines.iter().any(|line| line.trim() == new_line.trim()) {
+        return Some(text.to_string());
+    }
+
+    let index = lines
+        .iter()
+        .rposition(|line| line_day(line).is_some_and(|line_day| line_day < day))?;
+
+    let mut result: Vec<&str> = lines[..=index].to_vec();
+    result.push(new_line);
+    result.extend(&lines[index + 1..]);
+
+    Some(result.join("\n") + "\n")
+}
+
+/// `file_path` with the line registering `day`, read but not written yet.
+fn registered(
+    file_path: &Path,
+    day: u32,
+    new_line: &str,
+) -> Result<(PathBuf, String, String), String> {
+    let text = fs::read_to_string(file_path)
+        .map_err(|error| format!("Could not read {:?}: {}", file_path, error))?;
+    let registered_text = insert_day_line(&text, day, new_line).ok_or_else(|| {
+        format!(
+            "Could not find where to register day {} in {:?}",
+            day, file_path
+        )
+    })?;
+
+    Ok((file_path.to_path_buf(), text, registered_text))
+}
+
+fn write_file(file_path: &Path, content: &str) -> Result<(), String> {
+    fs::write(file_path, content)
+        .map_err(|error| format!("Could not write {:?}: {}", file_path, error))
+}
+
+/// Write the files of the day crate in `day_dir`.
+fn create_day_dir(day_dir: &Path, day: u32, title: &str) -> Result<(), String> {
+    fs::create_dir_all(day_dir.join("src"))
+        .and_then(|_| fs::create_dir_all(day_dir.join("input")))
+        .map_err(|error| format!("Could not create {:?}: {}", day_dir, error))?;
+
+    write_file(
+        &day_dir.join("Cargo.toml"),
+        &render(CARGO_TEMPLATE, day, title),
+    )?;
+    write_file(
+        &day_dir.join("src/main.rs"),
+        &render(MAIN_TEMPLATE, day, title),
+    )?;
+    write_file(
+        &day_dir.join("src/lib.rs"),
+        &render(LIB_TEMPLATE, day, title),
+    )?;
+    write_file(
+        &day_dir.join("input/answers.toml"),
+        &render(ANSWERS_TEMPLATE, day, title),
+    )?;
+    write_file(&day_dir.join("input/input.txt"), "")?;
+    write_file(&day_dir.join("input/example-1.txt"), "")
+}
+
+/// Create `day-N` in `workspace` and register it with the workspace, the runner and
+/// the VS Code launch configuration.
+///
+/// Every registry is checked before anything is written, and a failure while writing
+/// removes the day and restores the registries already written.
+pub fn new_day(workspace: &Path, day: u32, title: &str) -> Result<(), String> {
+    let day_dir = workspace.join(format!("day-{}", day));
+
+    // The title also ends up in a doc comment, where a line break would end it
+    if title.contains(char::is_control) {
+        return Err(format!("The title {:?} should fit on one line", title));
+    }
+    if day_dir.exists() {
+        return Err(format!("{:?} already exists", day_dir));
+    }
+
+    let registries = [
+        registered(
+            &workspace.join("Cargo.toml"),
+            day,
+            &format!("    \"day-{}\",", day),
+        )?,
+        registered(
+            &workspace.join("aoc/Cargo.toml"),
+            day,
+            &format!(
+                "day-{0} = {{ path = \"../day-{0}\", version = \"0.1.0\" }}",
+                day
+            ),
+        )?,
+        registered(
+            &workspace.join("aoc/src/days.rs"),
+            day,
+            &format!("        Box::new(day_{0}::Day{0}),", day),
+        )?,
+        registered(
+            &workspace.join(".vscode/launch.json"),
+            day,
+            &format!("                \"day-{}\",", day),
+        )?,
+    ];
+
+    let mut written = 0;
+    let result = create_day_dir(&day_dir, day, title).and_then(|_| {
+        for (file_path, _, registered_text) in &registries {
+            write_file(file_path, registered_text)?;
+            written += 1;
+        }
+        Ok(())
+    });
+
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&day_dir);
+        for (file_path, text, _) in &registries[..written] {
+            let _ = fs::write(file_path, text);
+        }
+    }
+
+    result
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn nominal_insert_day_line() {
+        let text = "[\n    \"day-1\",\n    \"day-2\",\n    \"day-10\",\n]\n";
+
+        assert_eq!(
+            insert_day_line(text, 3, "    \"day-3\",").unwrap(),
+            "[\n    \"day-1\",\n    \"day-2\",\n    \"day-3\",\n    \"day-10\",\n]\n"
+        );
+        assert_eq!(insert_day_line(text, 2, "    \"day-2\",").unwrap(), text);
+        assert_eq!(insert_day_line("[\n]\n", 1, "    \"day-1\","), None);
+    }
+
+    #[test]
+    fn nominal_new_day() {
+        let workspace = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let registries = [
+            "Cargo.toml",
+            "aoc/Cargo.toml",
+            "aoc/src/days.rs",
+            ".vscode/launch.json",
+        ];
+
+        for registry in registries {
+            let target = workspace.join(registry);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::copy(days::workspace_dir().join(registry), target).unwrap();
+        }
+
+        new_day(&workspace, 25, "Full of Hot Air").unwrap();
+
+        let lib = fs::read_to_string(workspace.join("day-25/src/lib.rs")).unwrap();
+        let days_rs = fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap();
+        let members = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+
+        assert!(lib.contains("const NAME: &'static str = \"--- Day 25: Full of Hot Air ---\";"));
+        assert!(lib.starts_with("//! --- Day 25: Full of Hot Air ---\n"));
+        assert!(lib.contains("common::example_tests!(Day25, example_1 => 1);"));
+        assert!(workspace.join("day-25/input/example-1.txt").is_file());
+        assert!(days_rs.contains("        Box::new(day_25::Day25),\n    ]"));
+        assert!(members.contains("    \"day-25\",\n]"));
+        assert!(new_day(&workspace, 25, "Again").is_err());
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+
+    #[test]
+    fn nominal_render_escaped_title() {
+        assert_eq!(
+            render(
+                "const NAME: &str = {{name}};",
+                13,
+                r#"The "Distress" \ Signal"#
+            ),
+            r#"const NAME: &str = "--- Day 13: The \"Distress\" \\ Signal ---";"#
+        );
+    }
+
+    #[test]
+    fn invalid_new_day() {
+        let workspace =
+            std::env::temp_dir().join(format!("aoc-invalid-day-{}", std::process::id()));
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::copy(
+            days::workspace_dir().join("Cargo.toml"),
+            workspace.join("Cargo.toml"),
+        )
+        .unwrap();
+        // Registries without any earlier day to insert the new one after
+        fs::write(workspace.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+
+        assert!(new_day(&workspace, 13, "Two\nLines").is_err());
+        assert!(new_day(&workspace, 13, "Distress Signal").is_err());
+        assert!(!workspace.join("day-13").exists());
+        assert_eq!(
+            fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+            fs::read_to_string(days::workspace_dir().join("Cargo.toml")).unwrap()
+        );
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+}
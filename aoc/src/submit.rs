@@ -0,0 +1,189 @@
+//! `submit` subcommand - post an answer and remember what the website said about it.
+
+use crate::client::{self, Client, ClientConfig, SubmitOutcome};
+use crate::days;
+use common::{read_input, Answer, AnswerRegistry, AnySolution, InputSource};
+use std::path::Path;
+
+/// Answer of `part` on the puzzle input, solved when it was not given.
+fn get_answer(
+    solution: &dyn AnySolution,
+    part: usize,
+    answer: Option<&str>,
+) -> Result<Answer, String> {
+    if let Some(answer) = answer {
+        return Ok(answer.parse().unwrap());
+    }
+
+    let input_dir = days::input_dir(solution.day());
+    let file_content =
+        read_input(&InputSource::Puzzle, &input_dir, false).map_err(|error| error.to_string())?;
+    let input = solution
+        .parse_any(&file_content)
+        .map_err(|error| error.snippet(&file_content))?;
+
+    Ok(solution.solve_any(input.as_ref(), part))
+}
+
+/// Submit `part` of `day`, refusing answers already known to be right or wrong.
+///
+/// Returns `Ok(true)` only when the answer is right.
+pub fn submit(day: u32, part: usize, answer: Option<&str>) -> Result<bool, String> {
+    let days = days::all();
+    let solution = days
+        .iter()
+        .find(|solution| solution.day() == day)
+        .ok_or_else(|| format!("Day {} is not available", day))?;
+
+    let answer = get_answer(solution.as_ref(), part, answer)?;
+    let mut client = Client::new(ClientConfig::load(days::workspace_dir())?)
+        .with_throttle_file(client::throttle_path());
+
+    submit_answer(&mut client, &days::input_dir(day), day, part, answer)
+}
+
+/// Submit `answer` with `client` and record the verdict in the answers of `input_dir`.
+fn submit_answer(
+    client: &mut Client,
+    input_dir: &Path,
+    day: u32,
+    part: usize,
+    answer: Answer,
+) -> Result<bool, String> {
+    let input_name = InputSource::Puzzle.name().unwrap();
+    let mut registry = AnswerRegistry::load(input_dir).map_err(|error| error.to_string())?;
+
+    if let Answer::Grid(_) = answer {
+        return Err(format!(
+            "Day {} part {} is a picture, read it and submit the letters with --answer",
+            day, part
+        ));
+    }
+
+    match registry.get(&input_name, part) {
+        Some(known) if *known == answer => {
+            println!(
+                "Day {} part {}: {} is already known to be right",
+                day, part, answer
+            );
+            return Ok(true);
+        }
+        Some(known) => {
+            return Err(format!(
+                "Day {} part {}: not submitting {}, the right answer is already known to be {}",
+                day, part, answer, known
+            ))
+        }
+        None => {}
+    }
+
+    if registry.is_rejected(&input_name, part, &answer) {
+        return Err(format!(
+            "Day {} part {}: not submitting {}, it was already rejected",
+            day, part, answer
+        ));
+    }
+
+    let outcome = client.submit(day, part, &answer)?;
+
+    let message = match &outcome {
+        SubmitOutcome::Correct => "That's the right answer!".to_string(),
+        SubmitOutcome::TooHigh => "That's not the right answer, it is too high".to_string(),
+        SubmitOutcome::TooLow => "That's not the right answer, it is too low".to_string(),
+        SubmitOutcome::Wrong => "That's not the right answer".to_string(),
+        SubmitOutcome::RateLimited(wait) => {
+            format!("Answered too recently, try again in {}s", wait.as_secs())
+        }
+        SubmitOutcome::WrongLevel => "This part is already solved or not unlocked yet".to_string(),
+        SubmitOutcome::Unknown(text) => format!("Unexpected answer from the website: {}", text),
+    };
+    println!("Day {} part {}: {} - {}", day, part, answer, message);
+
+    match outcome {
+        SubmitOutcome::Correct => registry.set(&input_name, part, answer),
+        SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong => {
+            registry.reject(&input_name, part, answer)
+        }
+        _ => return Ok(false),
+    }
+
+    registry
+        .save(input_dir)
+        .map_err(|error| error.to_string())?;
+
+    Ok(outcome == SubmitOutcome::Correct)
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{answer_page, config, serve, temp_input_dir};
+    use std::fs;
+
+    #[test]
+    fn nominal_submit_answer() {
+        let (base_url, requests) = serve(2, answer_page);
+        let input_dir = temp_input_dir("submit");
+        fs::create_dir_all(&input_dir).unwrap();
+        let mut client = Client::new(config(base_url, "valid", 0));
+
+        assert_eq!(
+            submit_answer(&mut client, &input_dir, 1, 1, Answer::from(3)),
+            Ok(false)
+        );
+        assert_eq!(
+            submit_answer(&mut client, &input_dir, 1, 1, Answer::from(24000)),
+            Ok(true)
+        );
+        assert!(requests.recv().unwrap().ends_with("level=1&answer=3"));
+        assert!(requests.recv().unwrap().ends_with("level=1&answer=24000"));
+
+        let registry = AnswerRegistry::load(&input_dir).unwrap();
+        assert_eq!(registry.get("input", 1), Some(&Answer::from(24000)));
+        assert!(registry.is_rejected("input", 1, &Answer::from(3)));
+
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn refused_submit_answer() {
+        let (base_url, requests) = serve(1, answer_page);
+        let input_dir = temp_input_dir("refused");
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(
+            input_dir.join("answers.toml"),
+            "[input]\npart_one = 24000\nrejected_part_two = [10]\n",
+        )
+        .unwrap();
+        let mut client = Client::new(config(base_url, "valid", 0));
+
+        assert_eq!(
+            submit_answer(&mut client, &input_dir, 1, 1, Answer::from(24000)),
+            Ok(true)
+        );
+        assert!(
+            submit_answer(&mut client, &input_dir, 1, 1, Answer::from(3))
+                .unwrap_err()
+                .contains("already known to be 24000")
+        );
+        assert!(
+            submit_answer(&mut client, &input_dir, 1, 2, Answer::from(10))
+                .unwrap_err()
+                .contains("already rejected")
+        );
+        assert!(submit_answer(
+            &mut client,
+            &input_dir,
+            1,
+            2,
+            Answer::Grid(vec!["#.".to_string()])
+        )
+        .unwrap_err()
+        .contains("is a picture"));
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+}
@@ -0,0 +1,113 @@
+//! `--verify` mode - solve every available input and compare with `input/answers.toml`.
+
+use crate::days;
+use common::{read_input, AnswerRegistry, AnySolution, InputSource, Verdict};
+
+#[derive(Debug, Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Summary {
+    fn add(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail(_) => self.failed += 1,
+            Verdict::Missing => self.missing += 1,
+        }
+    }
+}
+
+fn print_row(day: u32, input_name: &str, part: usize, status: &str) {
+    println!(
+        "Day {:>2}  {:<10} Part {}  {}",
+        day, input_name, part, status
+    );
+}
+
+/// Print `label` and the lines of `answer`, aligned under each other.
+fn print_answer(label: &str, answer: &str) {
+    for (i, line) in answer.lines().enumerate() {
+        if i == 0 {
+            println!("{:>18}: {}", label, line);
+        } else {
+            println!("{}{}", " ".repeat(20), line);
+        }
+    }
+}
+
+fn verify_input(
+    solution: &dyn AnySolution,
+    source: &InputSource,
+    registry: &AnswerRegistry,
+    parts: &[usize],
+    summary: &mut Summary,
+) {
+    let day = solution.day();
+    let input_name = source.name().expect("Available inputs should have a name");
+
+    let input = read_input(source, &days::input_dir(day), false)
+        .map_err(|error| error.to_string())
+        .and_then(|file_content| {
+            solution
+                .parse_any(&file_content)
+                .map_err(|error| error.snippet(&file_content))
+        });
+
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            for &part in parts {
+                print_row(day, &input_name, part, "ERROR");
+                summary.failed += 1;
+            }
+            eprintln!("{}", error);
+            return;
+        }
+    };
+
+    for &part in parts {
+        let answer = solution.solve_any(input.as_ref(), part);
+        let verdict = registry.check(&input_name, part, &answer);
+
+        print_row(day, &input_name, part, &verdict.to_string());
+
+        if let Verdict::Fail(expected) = &verdict {
+            print_answer("expected", &expected.to_string());
+            print_answer("got", &answer.to_string());
+        }
+
+        summary.add(&verdict);
+    }
+}
+
+/// Verify `parts` of every selected day. Returns `false` when any answer is wrong.
+pub fn verify(selected: &[&dyn AnySolution], parts: &[usize]) -> bool {
+    let mut summary = Summary::default();
+
+    for &solution in selected {
+        let input_dir = days::input_dir(solution.day());
+        let registry = match AnswerRegistry::load(&input_dir) {
+            Ok(registry) => registry,
+            Err(error) => {
+                eprintln!("Day {}: {}", solution.day(), error);
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for source in InputSource::available(&input_dir) {
+            verify_input(solution, &source, &registry, parts, &mut summary);
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    summary.failed == 0
+}
@@ -0,0 +1,132 @@
+//! `--visualize` and `--export` modes - play the simulation behind a day in the terminal,
+//! or draw it to a PNG or GIF file.
+
+use crate::days;
+use common::export::Exporter;
+use common::viz::{Player, Render};
+use common::{read_input, AnySolution, Config, InputSource};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the frames of a simulation go.
+#[derive(Clone, Copy)]
+pub enum Output<'a> {
+    Terminal(&'a Player),
+    /// A PNG of the last frame or a GIF of all of them, from the extension of the path
+    File(&'a Exporter, &'a Path),
+}
+
+/// `path` with `-part-N` added to its file name, e.g. `rope-part-2.gif`.
+fn part_path(path: &Path, part: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut file_name = format!("{}-part-{}", stem, part);
+
+    if let Some(extension) = path.extension() {
+        file_name += &format!(".{}", extension.to_string_lossy());
+    }
+
+    path.with_file_name(file_name)
+}
+
+/// Write the frames of `render` to `path`, returning a short report.
+fn export(render: &mut dyn Render, exporter: &Exporter, path: &Path) -> Result<String, String> {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some("png") => {
+            let mut last_frame = None;
+            while let Some(frame) = render.next_frame() {
+                last_frame = Some(frame);
+            }
+            let frame = last_frame.ok_or("nothing to draw")?;
+
+            exporter
+                .write_png(&frame, path)
+                .map_err(|error| error.to_string())?;
+            Ok(format!("wrote {:?}", path))
+        }
+        Some("gif") => {
+            let frames = exporter
+                .write_gif(render, path)
+                .map_err(|error| error.to_string())?;
+            Ok(format!("wrote {} frames to {:?}", frames, path))
+        }
+        _ => Err(format!("{:?} should end with .png or .gif", path)),
+    }
+}
+
+/// Play every part of `solution` in `parts` that has a visualization, one after the other,
+/// with the settings of `config`.
+pub fn visualize(
+    solution: &dyn AnySolution,
+    source: &InputSource,
+    parts: &[usize],
+    config: &Config,
+    output: Output,
+) -> bool {
+    let day = solution.day();
+
+    let file_content = match read_input(source, &days::input_dir(day), false) {
+        Ok(file_content) => file_content,
+        Err(error) => {
+            eprintln!("Day {}: {}", day, error);
+            return false;
+        }
+    };
+    let input = match crate::parse_configured(solution, &file_content, config) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {}: {}", day, error);
+            return false;
+        }
+    };
+
+    let mut success = true;
+
+    for &part in parts {
+        let Some(mut render) = solution.visualize_any(input.as_ref(), part) else {
+            eprintln!("Day {} part {} has no visualization", day, part);
+            success = false;
+            continue;
+        };
+
+        let result = match output {
+            Output::Terminal(player) => player
+                .play(render.as_mut(), &mut io::stdout())
+                .map(|frames| format!("{} frames", frames))
+                .map_err(|error| format!("could not play: {}", error)),
+            Output::File(exporter, path) if parts.len() > 1 => {
+                export(render.as_mut(), exporter, &part_path(path, part))
+            }
+            Output::File(exporter, path) => export(render.as_mut(), exporter, path),
+        };
+
+        match result {
+            Ok(report) => println!("Day {} part {}: {}", day, part, report),
+            Err(error) => {
+                eprintln!("Day {} part {}: {}", day, part, error);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_part_path() {
+        assert_eq!(
+            part_path(Path::new("out/rope.gif"), 2),
+            PathBuf::from("out/rope-part-2.gif")
+        );
+        assert_eq!(part_path(Path::new("crt"), 1), PathBuf::from("crt-part-1"));
+    }
+}
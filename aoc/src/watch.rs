@@ -0,0 +1,266 @@
+//! `--watch` mode - solve a day again whenever one of its inputs or sources changes.
+
+use crate::{days, solve_day, Cli};
+use common::viz::Colour;
+use common::{format_results, Answer, AnySolution, InputSource, OutputFormat};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// Editors often save a file in several steps, only the last one is solved.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Answers of the run before a restart, so they are still compared after it.
+const PREVIOUS_ANSWERS_VAR: &str = "AOC_WATCH_ANSWERS";
+
+/// Lines telling which answers of `current` differ from `previous`, in yellow when
+/// `colour` is set.
+fn changed_answers(
+    previous: &[(usize, Answer)],
+    current: &[(usize, Answer)],
+    colour: bool,
+) -> String {
+    let part_name = ["One", "Two"];
+    let mut text = String::new();
+
+    for (part, answer) in current {
+        let Some((_, before)) = previous.iter().find(|(before_part, _)| before_part == part) else {
+            continue;
+        };
+        if before == answer {
+            continue;
+        }
+
+        // Grids do not fit on one line
+        let line = if before.lines().len() == 1 && answer.lines().len() == 1 {
+            format!(
+                "Part {} changed: {} -> {}",
+                part_name[part - 1],
+                before,
+                answer
+            )
+        } else {
+            format!("Part {} changed", part_name[part - 1])
+        };
+
+        if colour {
+            text += &format!("    {}\n", Colour::Yellow.paint(&line));
+        } else {
+            text += &format!("    {}\n", line);
+        }
+    }
+
+    text
+}
+
+/// Block until some files change, then wait for them to settle. Returns the changed
+/// paths, or `None` once the watcher is gone.
+fn next_changes(receiver: &Receiver<notify::Result<Event>>) -> Option<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    let mut event = receiver.recv().ok()?;
+
+    loop {
+        match event {
+            // Solving reads the input, which should not trigger another run
+            Ok(Event {
+                kind: EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_)),
+                ..
+            }) => {}
+            Ok(event) => paths.extend(event.paths),
+            Err(error) => eprintln!("Watch error: {}", error),
+        }
+
+        event = match receiver.recv_timeout(SETTLE_TIME) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) if !paths.is_empty() => return Some(paths),
+            Err(RecvTimeoutError::Timeout) => receiver.recv().ok()?,
+            Err(RecvTimeoutError::Disconnected) => return None,
+        };
+    }
+}
+
+/// Rebuild the runner with the profile it was built with, then replace the running one
+/// with `executable` and the same arguments. Only returns when either step failed.
+fn restart(executable: &Path, answers: &[(usize, Answer)]) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut build = Command::new(cargo);
+    build
+        .args(["build", "-p", "aoc"])
+        .current_dir(days::workspace_dir());
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+
+    if !build.status().is_ok_and(|status| status.success()) {
+        eprintln!("Build failed, still solving with the previous one");
+        return;
+    }
+
+    let mut command = Command::new(executable);
+    command.args(env::args_os().skip(1)).env(
+        PREVIOUS_ANSWERS_VAR,
+        serde_json::to_string(answers).expect("Answers should serialize"),
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let error = command.exec();
+        eprintln!("Could not restart {:?}: {}", executable, error);
+    }
+    #[cfg(not(unix))]
+    match command.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(error) => eprintln!("Could not restart {:?}: {}", executable, error),
+    }
+}
+
+/// Solve `solution` as the plain runner would, then again whenever a file of its day
+/// directory (inputs, `config.toml`) or the `--input` file changes, highlighting the
+/// answers that changed.
+///
+/// A change of the Rust sources of the day or of `common` rebuilds and restarts the
+/// runner instead. Only returns, with `false`, when the files cannot be watched.
+pub fn watch(solution: &dyn AnySolution, cli: &Cli) -> bool {
+    if matches!(cli.input, Some(InputSource::Stdin)) {
+        eprintln!("The standard input cannot be watched");
+        return false;
+    }
+
+    let day = solution.day();
+    let day_dir = days::day_dir(day);
+    let source_dirs = [
+        day_dir.join("src"),
+        days::workspace_dir().join("common").join("src"),
+    ];
+    // Rebuilding replaces the file, which the running process would no longer find
+    let executable = match env::current_exe() {
+        Ok(executable) => executable,
+        Err(error) => {
+            eprintln!("Could not find the runner executable: {}", error);
+            return false;
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            eprintln!("Could not watch for changes: {}", error);
+            return false;
+        }
+    };
+
+    // The day directory holds its sources, so only those of `common` are added
+    let mut watched: Vec<&Path> = vec![&day_dir, &source_dirs[1]];
+    if let Some(InputSource::Path(path)) = &cli.input {
+        watched.push(path);
+    }
+
+    for path in watched {
+        if let Err(error) = watcher.watch(path, RecursiveMode::Recursive) {
+            eprintln!("Could not watch {:?}: {}", path, error);
+            return false;
+        }
+    }
+
+    let colour = io::stdout().is_terminal();
+    let mut previous: Vec<(usize, Answer)> = env::var(PREVIOUS_ANSWERS_VAR)
+        .ok()
+        .and_then(|answers| serde_json::from_str(&answers).ok())
+        .unwrap_or_default();
+
+    loop {
+        // Sources may change without a new solution version, so nothing is cached
+        let output = solve_day(solution, cli, None);
+        let answers: Vec<(usize, Answer)> = output
+            .results
+            .iter()
+            .map(|result| (result.part, result.answer.clone()))
+            .collect();
+
+        print!("{}", output.stdout);
+        if cli.format == OutputFormat::Text {
+            print!("{}", changed_answers(&previous, &answers, colour));
+        } else {
+            print!("{}", format_results(&output.results, cli.format));
+        }
+        eprint!("{}", output.stderr);
+        eprintln!("Watching day {} for changes, Ctrl-C to stop", day);
+
+        // A broken input keeps the last answers to compare the fixed one with
+        if !answers.is_empty() {
+            previous = answers;
+        }
+
+        let Some(paths) = next_changes(&receiver) else {
+            return false;
+        };
+
+        let sources_changed = paths.iter().any(|path| {
+            path.extension().is_some_and(|extension| extension == "rs")
+                && source_dirs.iter().any(|dir| path.starts_with(dir))
+        });
+        if sources_changed {
+            eprintln!("Sources of day {} changed, rebuilding", day);
+            restart(&executable, &previous);
+        }
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind};
+
+    #[test]
+    fn nominal_changed_answers() {
+        let previous = [(1, Answer::from(24000)), (2, Answer::from(45000))];
+        let current = [(1, Answer::from(24000)), (2, Answer::from(41000))];
+
+        assert_eq!(changed_answers(&previous, &previous, false), "");
+        assert_eq!(
+            changed_answers(&previous, &current, false),
+            "    Part Two changed: 45000 -> 41000\n"
+        );
+        assert_eq!(
+            changed_answers(&previous, &current, true),
+            "    \x1b[33mPart Two changed: 45000 -> 41000\x1b[0m\n"
+        );
+        assert_eq!(changed_answers(&[], &current, false), "");
+    }
+
+    #[test]
+    fn nominal_next_changes() {
+        let (sender, receiver) = mpsc::channel();
+        let event = |kind, path: &str| Ok(Event::new(kind).add_path(PathBuf::from(path)));
+
+        sender
+            .send(event(EventKind::Access(AccessKind::Any), "input.txt"))
+            .unwrap();
+        sender
+            .send(event(EventKind::Create(CreateKind::File), "example-1.txt"))
+            .unwrap();
+        sender
+            .send(event(EventKind::Modify(ModifyKind::Any), "example-1.txt"))
+            .unwrap();
+
+        assert_eq!(
+            next_changes(&receiver),
+            Some(vec![
+                PathBuf::from("example-1.txt"),
+                PathBuf::from("example-1.txt")
+            ])
+        );
+
+        drop(sender);
+        assert_eq!(next_changes(&receiver), None);
+    }
+}
@@ -0,0 +1,44 @@
+//! Counting is switched on for the whole process once enabled, so the counting allocator
+//! is tested in its own binary rather than next to the other tests of the runner.
+
+#[path = "../src/alloc.rs"]
+mod alloc;
+
+use common::MemoryStats;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+#[test]
+fn nominal_measured() {
+    let (_, stats) = alloc::measured(|| vec![0u8; 10]);
+    assert_eq!(stats, None);
+
+    alloc::enable();
+
+    let (vector, stats) = alloc::measured(|| vec![0u8; 1000]);
+    assert_eq!(vector.len(), 1000);
+    assert_eq!(
+        stats,
+        Some(MemoryStats {
+            allocations: 1,
+            allocated: 1000,
+            peak: 1000,
+        })
+    );
+
+    let (_, stats) = alloc::measured(|| {
+        drop(vec![0u8; 1000]);
+        let mut vector = Vec::<u8>::with_capacity(100);
+        vector.reserve_exact(200);
+        vector
+    });
+    assert_eq!(
+        stats,
+        Some(MemoryStats {
+            allocations: 3,
+            allocated: 1300,
+            peak: 1000,
+        })
+    );
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Answer to one part of a puzzle.
+///
+/// Serialized untagged, so integers stay numbers, text stays a string and a grid
+/// becomes a list of its lines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Lines of the answer as they should be printed.
+    pub fn lines(&self) -> Vec<String> {
+        match self {
+            Answer::Grid(grid) => grid.clone(),
+            _ => vec![self.to_string()],
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(grid) => write!(f, "{}", grid.join("\n")),
+        }
+    }
+}
+
+/// Parse an answer typed by a human, e.g. from an answers file or the command line.
+///
+/// Numbers become [`Answer::Integer`], multi-line text becomes [`Answer::Grid`] and
+/// anything else [`Answer::Text`], so `"42".parse()` equals `Answer::from(42)`.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim_matches(|c| c == '\r' || c == '\n');
+
+        if let Ok(integer) = value.trim().parse::<i64>() {
+            Ok(Answer::Integer(integer))
+        } else if value.contains('\n') {
+            Ok(Answer::Grid(
+                value.lines().map(|line| line.to_string()).collect(),
+            ))
+        } else {
+            Ok(Answer::Text(value.to_string()))
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(
+                        i64::try_from(value).expect("Answer should fit into a 64-bit integer"),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Grid(value)
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_answer_from_str() {
+        assert_eq!("42".parse::<Answer>().unwrap(), Answer::from(42));
+        assert_eq!("CMZ".parse::<Answer>().unwrap(), Answer::from("CMZ"));
+        assert_eq!(
+            "\n##..\n..##\n".parse::<Answer>().unwrap(),
+            Answer::Grid(vec!["##..".to_string(), "..##".to_string()])
+        );
+    }
+
+    #[test]
+    fn nominal_answer_serialization() {
+        let answers = vec![
+            Answer::from(13140),
+            Answer::from("CMZ"),
+            Answer::Grid(vec!["#.".to_string(), ".#".to_string()]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+
+        assert_eq!(json, r##"[13140,"CMZ",["#.",".#"]]"##);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+}
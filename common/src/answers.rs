@@ -0,0 +1,353 @@
+use crate::Answer;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+pub static ANSWERS_FILE: &str = "answers.toml";
+
+/// Keys of the table of an input, in the order of [`KnownAnswers`].
+const ANSWER_KEYS: [&str; 4] = [
+    "part_one",
+    "part_two",
+    "rejected_part_one",
+    "rejected_part_two",
+];
+
+/// Known answers to both parts of one input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+    /// Answers the puzzle website said were wrong
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_part_one: Vec<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_part_two: Vec<Answer>,
+}
+
+impl KnownAnswers {
+    pub fn part(&self, part: usize) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn rejected(&self, part: usize) -> &[Answer] {
+        match part {
+            1 => &self.rejected_part_one,
+            2 => &self.rejected_part_two,
+            _ => &[],
+        }
+    }
+}
+
+/// Known answers of a day, by input name (`input`, `example-1`, ...).
+///
+/// Stored next to the inputs as `answers.toml`, one table per input:
+///
+/// ```toml
+/// [example-1]
+/// part_one = 13140
+/// part_two = ["##..##..", "###...##"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerRegistry {
+    inputs: IndexMap<String, KnownAnswers>,
+}
+
+impl AnswerRegistry {
+    /// Load `answers.toml` from `input_dir`. A missing file is an empty registry.
+    pub fn load(input_dir: &Path) -> Result<Self, AnswersError> {
+        let file_path = input_dir.join(ANSWERS_FILE);
+
+        let file_content = match fs::read_to_string(&file_path) {
+            Ok(file_content) => file_content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(AnswerRegistry::default())
+            }
+            Err(error) => {
+                return Err(AnswersError {
+                    path: file_path,
+                    message: error.to_string(),
+                })
+            }
+        };
+
+        toml::from_str(&file_content).map_err(|error| AnswersError {
+            path: file_path,
+            message: error.message().to_string(),
+        })
+    }
+
+    /// Write the registry back to `answers.toml` in `input_dir`.
+    ///
+    /// The file is edited in place: only the answers that changed are rewritten, so its
+    /// comments and layout are kept.
+    pub fn save(&self, input_dir: &Path) -> Result<(), AnswersError> {
+        let file_path = input_dir.join(ANSWERS_FILE);
+        let error = |message: String| AnswersError {
+            path: file_path.clone(),
+            message,
+        };
+
+        let file_content = match fs::read_to_string(&file_path) {
+            Ok(file_content) => file_content,
+            Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(io_error) => return Err(error(io_error.to_string())),
+        };
+        let saved: AnswerRegistry = toml::from_str(&file_content)
+            .map_err(|toml_error| error(toml_error.message().to_string()))?;
+        let mut document: DocumentMut = file_content
+            .parse()
+            .map_err(|toml_error: toml_edit::TomlError| error(toml_error.message().to_string()))?;
+
+        self.update_document(&saved, &mut document)
+            .map_err(|ser_error| error(ser_error.to_string()))?;
+
+        fs::write(&file_path, document.to_string()).map_err(|io_error| error(io_error.to_string()))
+    }
+
+    /// Rewrite in `document`, which holds the `saved` registry, the answers that differ.
+    fn update_document(
+        &self,
+        saved: &AnswerRegistry,
+        document: &mut DocumentMut,
+    ) -> Result<(), toml_edit::ser::Error> {
+        for (input_name, known_answers) in &self.inputs {
+            let values = toml_edit::ser::to_document(known_answers)?;
+            let saved_values = toml_edit::ser::to_document(
+                &saved.inputs.get(input_name).cloned().unwrap_or_default(),
+            )?;
+
+            if !document.get(input_name).is_some_and(Item::is_table) {
+                let mut table = Table::new();
+                // A blank line between tables, as `toml` writes them
+                if !document.is_empty() {
+                    table.decor_mut().set_prefix("\n");
+                }
+                document.insert(input_name, Item::Table(table));
+            }
+            let table = document[input_name.as_str()]
+                .as_table_mut()
+                .expect("Input should have a table");
+
+            for key in ANSWER_KEYS {
+                let value = values.get(key).and_then(Item::as_value);
+                let saved_value = saved_values.get(key).and_then(Item::as_value);
+                if value.map(Value::to_string) == saved_value.map(Value::to_string) {
+                    continue;
+                }
+
+                match value {
+                    Some(value) => {
+                        let mut value = value.clone();
+                        // Keep the comment after the replaced answer, if any
+                        if let Some(old_value) = table.get(key).and_then(Item::as_value) {
+                            *value.decor_mut() = old_value.decor().clone();
+                        }
+                        table.insert(key, Item::Value(value));
+                    }
+                    None => {
+                        table.remove(key);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, input_name: &str, part: usize) -> Option<&Answer> {
+        self.inputs
+            .get(input_name)
+            .and_then(|known_answers| known_answers.part(part))
+    }
+
+    /// Record `answer` as the right answer of `part` on `input_name`.
+    pub fn set(&mut self, input_name: &str, part: usize, answer: Answer) {
+        let known_answers = self.inputs.entry(input_name.to_string()).or_default();
+
+        match part {
+            1 => known_answers.part_one = Some(answer),
+            _ => known_answers.part_two = Some(answer),
+        }
+    }
+
+    /// Record `answer` as a wrong answer of `part` on `input_name`.
+    pub fn reject(&mut self, input_name: &str, part: usize, answer: Answer) {
+        let known_answers = self.inputs.entry(input_name.to_string()).or_default();
+        let rejected = match part {
+            1 => &mut known_answers.rejected_part_one,
+            _ => &mut known_answers.rejected_part_two,
+        };
+
+        if !rejected.contains(&answer) {
+            rejected.push(answer);
+        }
+    }
+
+    pub fn is_rejected(&self, input_name: &str, part: usize, answer: &Answer) -> bool {
+        self.inputs
+            .get(input_name)
+            .is_some_and(|known_answers| known_answers.rejected(part).contains(answer))
+    }
+
+    /// Compare `answer` with the known answer of `part` on `input_name`.
+    pub fn check(&self, input_name: &str, part: usize, answer: &Answer) -> Verdict {
+        match self.get(input_name, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// Outcome of checking an answer against the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// Holds the expected answer
+    Fail(Answer),
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AnswersError {
+    path: PathBuf,
+    message: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Could not load answers from {:?}: {}",
+            self.path,
+            self.message.trim_end()
+        )
+    }
+}
+
+impl Error for AnswersError {}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_answer_registry() {
+        let registry: AnswerRegistry = toml::from_str(
+            r##"
+            [input]
+            part_one = 24000
+            part_two = "CMZ"
+
+            [example-1]
+            part_two = ["#.", ".#"]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            registry.check("input", 1, &Answer::from(24000)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            registry.check("input", 2, &Answer::from("MCD")),
+            Verdict::Fail(Answer::from("CMZ"))
+        );
+        assert_eq!(
+            registry.check("example-1", 1, &Answer::from(1)),
+            Verdict::Missing
+        );
+        assert_eq!(
+            registry.get("example-1", 2),
+            Some(&Answer::Grid(vec!["#.".to_string(), ".#".to_string()]))
+        );
+        assert_eq!(
+            registry.check("example-2", 1, &Answer::from(1)),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn nominal_record_answers() {
+        let mut registry: AnswerRegistry = toml::from_str("[input]\npart_one = 1\n").unwrap();
+
+        registry.reject("input", 2, Answer::from(10));
+        registry.reject("input", 2, Answer::from(10));
+        registry.set("input", 2, Answer::from(12));
+        registry.set("example-1", 1, Answer::from(3));
+
+        assert!(registry.is_rejected("input", 2, &Answer::from(10)));
+        assert!(!registry.is_rejected("input", 1, &Answer::from(10)));
+        assert_eq!(
+            toml::to_string(&registry).unwrap(),
+            "[input]\npart_one = 1\npart_two = 12\nrejected_part_two = [10]\n\n\
+            [example-1]\npart_one = 3\n"
+        );
+    }
+
+    #[test]
+    fn nominal_save_in_place() {
+        let input_dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(
+            input_dir.join(ANSWERS_FILE),
+            "# Answers of day 1\n\
+            [input]\n\
+            part_one = 'CMZ' # from the website\n\
+            part_two =   24000  # too low\n",
+        )
+        .unwrap();
+
+        let mut registry = AnswerRegistry::load(&input_dir).unwrap();
+        registry.set("input", 2, Answer::from(45000));
+        registry.reject("input", 2, Answer::from(24000));
+        registry.set("example-1", 1, Answer::from(3));
+        registry.save(&input_dir).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(input_dir.join(ANSWERS_FILE)).unwrap(),
+            "# Answers of day 1\n\
+            [input]\n\
+            part_one = 'CMZ' # from the website\n\
+            part_two =   45000  # too low\n\
+            rejected_part_two = [24000]\n\
+            \n\
+            [example-1]\n\
+            part_one = 3\n"
+        );
+        assert_eq!(AnswerRegistry::load(&input_dir).unwrap(), registry);
+
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn missing_answers_file() {
+        let registry = AnswerRegistry::load(Path::new("./missing")).unwrap();
+        assert_eq!(registry, AnswerRegistry::default());
+    }
+}
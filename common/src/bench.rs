@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Run `f` and return its result together with the time it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// Summary of the timings of one phase repeated several times.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Stats need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Stats {
+            samples: count,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Human readable duration with a unit fitting its size, e.g. `12.35 ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1e3 {
+        format!("{:.0} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+/// Heap use of one run of a phase, as counted by the runner's allocator with `--alloc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct MemoryStats {
+    /// Allocations made, reallocations included
+    pub allocations: u64,
+    /// Bytes requested by these allocations
+    #[serde(rename = "allocated_bytes")]
+    pub allocated: u64,
+    /// Most bytes held at once on top of what was held before the run
+    #[serde(rename = "peak_bytes")]
+    pub peak: u64,
+}
+
+/// Human readable size with a binary unit fitting it, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", size, units[unit])
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_stats() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean.as_micros(), 5000);
+        // sqrt((1 + 9 + 9 + 1) / 4) = sqrt(5)
+        assert_eq!(stats.stddev.as_micros(), 2236);
+    }
+
+    #[test]
+    fn nominal_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35 µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25 s");
+    }
+
+    #[test]
+    fn nominal_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00 MiB");
+        assert_eq!(format_bytes(5 << 40), "5120.00 GiB");
+    }
+}
@@ -0,0 +1,182 @@
+use serde::de::DeserializeOwned;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub static CONFIG_FILE: &str = "config.toml";
+
+/// Tunable constants of a day, by name, as written in its `config.toml` or with
+/// `--set key=value`.
+///
+/// Days read them through their own typed struct with [`Config::to_typed`], where a
+/// missing setting keeps the value of the puzzle statement:
+///
+/// ```toml
+/// rounds_part_two = 20000
+/// relief = 2
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    table: toml::Table,
+}
+
+impl Config {
+    /// Load `config.toml` from `day_dir`. A missing file is an empty config.
+    pub fn load(day_dir: &Path) -> Result<Self, ConfigError> {
+        let file_path = day_dir.join(CONFIG_FILE);
+
+        match fs::read_to_string(&file_path) {
+            Ok(file_content) => Config::parse(&file_content)
+                .map_err(|error| ConfigError::new(format!("{:?}: {}", file_path, error))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(ConfigError::new(format!("{:?}: {}", file_path, error))),
+        }
+    }
+
+    pub fn parse(file_content: &str) -> Result<Self, ConfigError> {
+        let table = file_content
+            .parse()
+            .map_err(|error: toml::de::Error| ConfigError::new(error.message()))?;
+
+        Ok(Config { table })
+    }
+
+    /// Override one setting with `key=value`, the value written as in TOML. A value
+    /// that is not valid TOML is taken as a string, so `--set name=abc` needs no quotes.
+    pub fn set(&mut self, assignment: &str) -> Result<(), ConfigError> {
+        let (key, value) = assignment.split_once('=').ok_or_else(|| {
+            ConfigError::new(format!("Expected key=value, found `{}`", assignment))
+        })?;
+        let (key, value) = (key.trim(), value.trim());
+
+        if key.is_empty() {
+            return Err(ConfigError::new(format!("Missing key in `{}`", assignment)));
+        }
+
+        // The value is parsed as the right hand side of a one line document
+        let value = format!("value = {}", value)
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+        self.table.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// The settings as the typed constants of a day, which should use
+    /// `#[serde(default, deny_unknown_fields)]` so settings can be left out but not
+    /// misspelled.
+    pub fn to_typed<T: DeserializeOwned>(&self) -> Result<T, ConfigError> {
+        toml::Value::Table(self.table.clone())
+            .try_into()
+            .map_err(|error: toml::de::Error| ConfigError::new(error.message()))
+    }
+}
+
+/// The settings as a TOML document, the same for the same settings.
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.table)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    message: String,
+}
+
+impl ConfigError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ConfigError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid configuration: {}", self.message.trim_end())
+    }
+}
+
+impl Error for ConfigError {}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Constants {
+        rounds: usize,
+        probes: Vec<u32>,
+        name: String,
+    }
+
+    impl Default for Constants {
+        fn default() -> Self {
+            Constants {
+                rounds: 20,
+                probes: vec![20, 60],
+                name: "monkeys".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn nominal_config() {
+        let mut config = Config::parse("rounds = 30\n").unwrap();
+        assert_eq!(
+            config.to_typed::<Constants>().unwrap(),
+            Constants {
+                rounds: 30,
+                ..Constants::default()
+            }
+        );
+
+        config.set("probes = [1, 2, 3]").unwrap();
+        config.set("name=apes").unwrap();
+        config.set("rounds=40").unwrap();
+        assert_eq!(
+            config.to_typed::<Constants>().unwrap(),
+            Constants {
+                rounds: 40,
+                probes: vec![1, 2, 3],
+                name: "apes".to_string(),
+            }
+        );
+        assert_eq!(
+            config.to_string(),
+            "name = \"apes\"\nprobes = [1, 2, 3]\nrounds = 40\n"
+        );
+        assert_eq!(
+            Config::default().to_typed::<Constants>().unwrap(),
+            Constants::default()
+        );
+    }
+
+    #[test]
+    fn invalid_config() {
+        let mut config = Config::default();
+        assert!(config.set("rounds").is_err());
+        assert!(config.set("=3").is_err());
+
+        config.set("round=3").unwrap();
+        let error = config.to_typed::<Constants>().unwrap_err();
+        assert!(error.to_string().contains("unknown field `round`"));
+
+        let config = Config::parse("rounds = \"many\"").unwrap();
+        assert!(config.to_typed::<Constants>().is_err());
+        assert!(Config::parse("rounds = ").is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{read_input, AnswerRegistry, InputSource, Solution};
+use std::path::Path;
+
+/// Solve `input/example-N.txt` of a day and check it against its `answers.toml`.
+///
+/// Used by [`example_tests!`](crate::example_tests), panics on any mismatch.
+pub fn check_example<S: Solution>(input_dir: &Path, number: u32) {
+    let source = InputSource::Example(number);
+    let input_name = source.name().unwrap();
+
+    let file_content =
+        read_input(&source, input_dir, false).unwrap_or_else(|error| panic!("{}", error));
+    let registry = AnswerRegistry::load(input_dir).unwrap_or_else(|error| panic!("{}", error));
+    let input =
+        S::parse(&file_content).unwrap_or_else(|error| panic!("{}", error.snippet(&file_content)));
+
+    let mut checked = 0;
+
+    for part in [1, 2] {
+        if let Some(expected) = registry.get(&input_name, part) {
+            let answer = match part {
+                1 => S::part_one(&input),
+                _ => S::part_two(&input),
+            };
+
+            assert_eq!(
+                &answer,
+                expected,
+                "Day {} {} part {}",
+                S::DAY,
+                input_name,
+                part
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(
+        checked > 0,
+        "Day {}: no known answers for {}",
+        S::DAY,
+        input_name
+    );
+}
+
+/// Generate one `#[test]` per example input of a day.
+///
+/// ```ignore
+/// common::example_tests!(Day9, example_1 => 1, example_2 => 2);
+/// ```
+///
+/// Each test solves `input/example-N.txt` of the calling crate and compares the
+/// answers with the `[example-N]` table of its `input/answers.toml`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $($name:ident => $number:expr),+ $(,)?) => {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    let input_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+                    $crate::check_example::<$solution>(&input_dir, $number);
+                }
+            )+
+        }
+    };
+}
@@ -0,0 +1,311 @@
+//! Still and animated pictures of [`Frame`]s, to share simulations outside the terminal.
+//!
+//! Every cell becomes a square of `scale` pixels in the colour the [`Palette`] gives it;
+//! characters themselves are not drawn.
+
+use crate::viz::{Cell, Frame, Render};
+use crate::Grid;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+pub type Rgb = [u8; 3];
+
+/// Pixel colours of the cells of a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    /// Blank cells, drawn as `' '`
+    pub background: Rgb,
+    /// Cells without a colour
+    pub plain: Rgb,
+    /// Cells of each [`Colour`](crate::viz::Colour), in declaration order
+    pub colours: [Rgb; 8],
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette {
+            background: [16, 16, 24],
+            plain: [44, 44, 56],
+            colours: [
+                [220, 50, 47],   // Red
+                [80, 200, 80],   // Green
+                [230, 200, 40],  // Yellow
+                [60, 120, 230],  // Blue
+                [200, 80, 200],  // Magenta
+                [40, 200, 200],  // Cyan
+                [128, 128, 144], // Grey
+                [255, 255, 255], // Highlight
+            ],
+        }
+    }
+}
+
+impl Palette {
+    /// Index of the colour of `cell` in [`Palette::table`].
+    fn index(&self, cell: &Cell) -> u8 {
+        match (cell.char, cell.colour) {
+            (' ', None) => 0,
+            (_, None) => 1,
+            (_, Some(colour)) => 2 + colour as u8,
+        }
+    }
+
+    /// Every colour as `[r, g, b, r, g, b, ...]`, background first.
+    fn table(&self) -> Vec<u8> {
+        [self.background, self.plain]
+            .iter()
+            .chain(&self.colours)
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct ExportError {
+    path: PathBuf,
+    message: String,
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Could not export to {:?}: {}", self.path, self.message)
+    }
+}
+
+impl Error for ExportError {}
+
+/// Writes frames to PNG and animated GIF files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exporter {
+    pub palette: Palette,
+    /// Side of the square of pixels drawn for each cell
+    pub scale: usize,
+    /// Frames per second of animations
+    pub fps: u32,
+    /// Largest part of a frame kept in animations, in cells, around its focus
+    pub viewport: (usize, usize),
+    /// Keep one frame out of `every` in animations, the last one always kept
+    pub every: usize,
+}
+
+impl Default for Exporter {
+    fn default() -> Exporter {
+        Exporter {
+            palette: Palette::default(),
+            scale: 4,
+            fps: 20,
+            viewport: (160, 100),
+            every: 1,
+        }
+    }
+}
+
+impl Exporter {
+    fn error(path: &Path, message: impl ToString) -> ExportError {
+        ExportError {
+            path: path.to_path_buf(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Palette indexes of the pixels of `indexes`, the palette indexes of cells, drawn at
+    /// the top left of a `size` cells picture filled with the background.
+    fn pixels(&self, indexes: &Grid<u8>, size: (usize, usize)) -> Vec<u8> {
+        let mut pixels = vec![0; size.0 * size.1 * self.scale * self.scale];
+        let line = size.0 * self.scale;
+
+        for (x, y) in indexes.positions() {
+            let index = indexes[(x, y)];
+
+            for pixel_y in y * self.scale..(y + 1) * self.scale {
+                let start = pixel_y * line + x * self.scale;
+                pixels[start..start + self.scale].fill(index);
+            }
+        }
+
+        pixels
+    }
+
+    /// Write the whole of `frame` to the PNG file `path`.
+    pub fn write_png(&self, frame: &Frame, path: &Path) -> Result<(), ExportError> {
+        let size = (frame.cells.width(), frame.cells.height());
+        let table = self.palette.table();
+        let data: Vec<u8> = self
+            .pixels(&frame.cells.map(|cell| self.palette.index(cell)), size)
+            .into_iter()
+            .flat_map(|index| &table[index as usize * 3..index as usize * 3 + 3])
+            .copied()
+            .collect();
+
+        let file = File::create(path).map_err(|error| Exporter::error(path, error))?;
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(file),
+            (size.0 * self.scale) as u32,
+            (size.1 * self.scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .map_err(|error| Exporter::error(path, error))?;
+        writer
+            .write_image_data(&data)
+            .and_then(|_| writer.finish())
+            .map_err(|error| Exporter::error(path, error))
+    }
+
+    /// Play `render` into the animated GIF file `path`, returning how many frames it holds.
+    pub fn write_gif(&self, render: &mut dyn Render, path: &Path) -> Result<usize, ExportError> {
+        let every = self.every.max(1);
+        let mut kept = Vec::new();
+        let mut skipped = None;
+        let mut count = 0;
+
+        while let Some(frame) = render.next_frame() {
+            // Only palette indexes are kept, long simulations make many frames
+            let cells = frame
+                .crop(self.viewport)
+                .map(|cell| self.palette.index(cell));
+
+            if count % every == 0 {
+                kept.push(cells);
+                skipped = None;
+            } else {
+                skipped = Some(cells);
+            }
+            count += 1;
+        }
+        kept.extend(skipped);
+
+        let size = (
+            kept.iter().map(Grid::width).max().unwrap_or(0),
+            kept.iter().map(Grid::height).max().unwrap_or(0),
+        );
+        let (width, height) = (size.0 * self.scale, size.1 * self.scale);
+
+        if width == 0 || height == 0 {
+            return Err(Exporter::error(path, "nothing to draw"));
+        }
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(Exporter::error(path, "picture too large, lower the scale"));
+        }
+
+        let file = File::create(path).map_err(|error| Exporter::error(path, error))?;
+        let mut encoder = gif::Encoder::new(
+            BufWriter::new(file),
+            width as u16,
+            height as u16,
+            &self.palette.table(),
+        )
+        .map_err(|error| Exporter::error(path, error))?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(|error| Exporter::error(path, error))?;
+
+        // Delays are in hundredths of a second, viewers play anything below 2 too slowly
+        let delay = (100 / self.fps.max(1)).max(2) as u16;
+
+        for (i, cells) in kept.iter().enumerate() {
+            let frame = gif::Frame {
+                width: width as u16,
+                height: height as u16,
+                // Linger on the result before looping
+                delay: if i + 1 == kept.len() { 200 } else { delay },
+                buffer: self.pixels(cells, size).into(),
+                ..gif::Frame::default()
+            };
+
+            encoder
+                .write_frame(&frame)
+                .map_err(|error| Exporter::error(path, error))?;
+        }
+
+        Ok(kept.len())
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::viz::Colour;
+    use std::fs;
+    use std::io::BufReader;
+
+    fn frame(width: usize) -> Frame {
+        let mut cells = Grid::new(width, 2, Cell::plain(' '));
+        cells[(0, 0)] = Cell::new('#', Colour::Green);
+        cells[(width - 1, 1)] = Cell::plain('.');
+
+        Frame {
+            cells,
+            caption: String::new(),
+            focus: None,
+        }
+    }
+
+    #[test]
+    fn nominal_pixels() {
+        let exporter = Exporter {
+            scale: 2,
+            ..Exporter::default()
+        };
+
+        assert_eq!(
+            exporter.pixels(
+                &frame(2).cells.map(|cell| exporter.palette.index(cell)),
+                (3, 2)
+            ),
+            [
+                3, 3, 0, 0, 0, 0, //
+                3, 3, 0, 0, 0, 0, //
+                0, 0, 1, 1, 0, 0, //
+                0, 0, 1, 1, 0, 0, //
+            ]
+        );
+    }
+
+    #[test]
+    fn nominal_write_png_and_gif() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let exporter = Exporter {
+            every: 2,
+            ..Exporter::default()
+        };
+
+        exporter
+            .write_png(&frame(3), &dir.join("frame.png"))
+            .unwrap();
+        let png = png::Decoder::new(BufReader::new(File::open(dir.join("frame.png")).unwrap()));
+        let info = png.read_info().unwrap();
+        assert_eq!((info.info().width, info.info().height), (12, 8));
+
+        // Frames 0, 2 and the last one, 3, are kept
+        let mut frames = (1..=4).map(frame);
+        let count = exporter
+            .write_gif(&mut frames, &dir.join("frames.gif"))
+            .unwrap();
+        assert_eq!(count, 3);
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut gif = options
+            .read_info(File::open(dir.join("frames.gif")).unwrap())
+            .unwrap();
+        assert_eq!((gif.width(), gif.height()), (16, 8));
+        let mut decoded = 0;
+        while gif.read_next_frame().unwrap().is_some() {
+            decoded += 1;
+        }
+        assert_eq!(decoded, 3);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
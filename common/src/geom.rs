@@ -0,0 +1,306 @@
+//! 2D geometry on integer coordinates.
+//!
+//! `x` grows to the right and `y` grows down, like the rows of a puzzle input, so
+//! [`Dir4::Up`] is `(0, -1)`.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Position on an unbounded plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Displacement between two [`Point2`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// Number of orthogonal steps to `other`.
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (other - self).manhattan_len()
+    }
+
+    /// Number of king moves to `other`, diagonals included.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (other - self).chebyshev_len()
+    }
+
+    /// `(x, y)` position in a [`Grid`](crate::Grid), `None` when a coordinate is negative.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan_len(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_len(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Vector of the signs of both coordinates, the single step towards its direction.
+    pub fn signum(self) -> Vec2 {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Point2 {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+/// Offset usable with [`Grid::step`](crate::Grid::step) and [`Grid::ray`](crate::Grid::ray).
+impl From<Vec2> for (isize, isize) {
+    fn from(vector: Vec2) -> (isize, isize) {
+        (vector.x as isize, vector.y as isize)
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add<Vec2> for Point2 {
+    type Output = Point2;
+
+    fn add(self, vector: Vec2) -> Point2 {
+        Point2::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point2 {
+    fn add_assign(&mut self, vector: Vec2) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vec2> for Point2 {
+    type Output = Point2;
+
+    fn sub(self, vector: Vec2) -> Point2 {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vec2> for Point2 {
+    fn sub_assign(&mut self, vector: Vec2) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Point2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        self + -other
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Direction from its letter (`U`, `R`, `D`, `L`) or its arrow (`^`, `>`, `v`, `<`).
+    pub fn from_char(value: char) -> Option<Dir4> {
+        match value {
+            'U' | '^' => Some(Dir4::Up),
+            'R' | '>' => Some(Dir4::Right),
+            'D' | 'v' => Some(Dir4::Down),
+            'L' | '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn vector(self) -> Vec2 {
+        match self {
+            Dir4::Up => Vec2::new(0, -1),
+            Dir4::Right => Vec2::new(1, 0),
+            Dir4::Down => Vec2::new(0, 1),
+            Dir4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 8 directions, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn vector(self) -> Vec2 {
+        match self {
+            Dir8::Up => Vec2::new(0, -1),
+            Dir8::UpRight => Vec2::new(1, -1),
+            Dir8::Right => Vec2::new(1, 0),
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::new(0, 1),
+            Dir8::DownLeft => Vec2::new(-1, 1),
+            Dir8::Left => Vec2::new(-1, 0),
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Next direction clockwise, an eighth of a turn.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Next direction counterclockwise, an eighth of a turn.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Dir8 {
+        Dir8::ALL[direction as usize * 2]
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_point_arithmetic() {
+        let start = Point2::new(1, -2);
+        let end = start + Vec2::new(3, 4) * 2;
+
+        assert_eq!(end, Point2::new(7, 6));
+        assert_eq!(end - start, Vec2::new(6, 8));
+        assert_eq!(end - Vec2::new(7, 7), Point2::new(0, -1));
+        assert_eq!(start.manhattan(end), 14);
+        assert_eq!(start.chebyshev(end), 8);
+        assert_eq!((start - end).signum(), Vec2::new(-1, -1));
+        assert_eq!(start.to_position(), None);
+        assert_eq!(Point2::from((3, 4)).to_position(), Some((3, 4)));
+    }
+
+    #[test]
+    fn nominal_dir4() {
+        assert_eq!(Dir4::from_char('R'), Some(Dir4::Right));
+        assert_eq!(Dir4::from_char('v'), Some(Dir4::Down));
+        assert_eq!(Dir4::from_char('X'), None);
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir4::Up.vector(), Vec2::new(0, -1));
+    }
+
+    #[test]
+    fn nominal_dir8() {
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+        assert_eq!(
+            Dir8::ALL
+                .iter()
+                .map(|dir| dir.vector())
+                .fold(Vec2::ZERO, Add::add),
+            Vec2::ZERO
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use crate::geom::{Dir4, Dir8};
+use crate::parse::{lines, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid of cells stored row after row.
+///
+/// Positions are `(x, y)` pairs, `x` being the column and `y` the row, with `(0, 0)` at
+/// the top left corner like in the puzzle pictures.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` x `height` cells, all set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid made of `rows`, or `None` when they do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse the characters of `input`, one row per non empty line, turning each one
+    /// into a cell with `cell`.
+    ///
+    /// Fails on the first character `cell` rejects, reported as not being `expected`,
+    /// and on the first row whose length differs from the first one.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        // Rows stay slices of the input, so errors point at their real line
+        let rows: Vec<&str> = lines(input).filter(|line| !line.is_empty()).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let mut cells = Vec::with_capacity(width * rows.len());
+
+        for row in &rows {
+            if row.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("a row of {} cells", width),
+                ));
+            }
+
+            for (index, char) in row.char_indices() {
+                let value = cell(char).ok_or_else(|| {
+                    ParseError::at(input, &row[index..index + char.len_utf8()], expected)
+                })?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    /// Position `offset` away from `position`, if it is still inside the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(position).then_some(position)
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.width * self.height).map(move |index| (index % width, index / width))
+    }
+
+    /// Every cell, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty row still makes no row at all
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Positions of the orthogonal neighbours of `position` inside the grid, in the
+    /// order of [`Dir4::ALL`].
+    pub fn neighbours_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.vector().into()))
+    }
+
+    /// Positions of the neighbours of `position` inside the grid, diagonals included, in
+    /// the order of [`Dir8::ALL`].
+    pub fn neighbours_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.vector().into()))
+    }
+
+    /// Positions met walking from `position` by steps of `offset` until the edge,
+    /// `position` itself excluded.
+    pub fn ray(
+        &self,
+        position: (usize, usize),
+        offset: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.step(position, offset), move |&next| {
+            self.step(next, offset)
+        })
+    }
+
+    /// Grid of the cells passed through `f`, keeping their positions.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid mirrored along its diagonal, rows becoming columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// Add `left`, `top`, `right` and `bottom` rows and columns of `value` around the grid.
+    ///
+    /// Positions of the existing cells move by `(left, top)`.
+    pub fn expand(&mut self, left: usize, top: usize, right: usize, bottom: usize, value: T)
+    where
+        T: Clone,
+    {
+        let width = left + self.width + right;
+        let mut cells = Vec::with_capacity(width * (top + self.height + bottom));
+        let mut old_cells = std::mem::take(&mut self.cells).into_iter();
+
+        cells.resize(width * top, value.clone());
+        for _ in 0..self.height {
+            cells.extend(std::iter::repeat_n(value.clone(), left));
+            cells.extend(old_cells.by_ref().take(self.width));
+            cells.extend(std::iter::repeat_n(value.clone(), right));
+        }
+        cells.resize(cells.len() + width * bottom, value);
+
+        self.width = width;
+        self.height += top + bottom;
+        self.cells = cells;
+    }
+}
+
+/// Empty grid, whatever the cells would be.
+impl<T> Default for Grid<T> {
+    fn default() -> Grid<T> {
+        Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {:?} should be inside the grid", position))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {:?} should be inside the grid", position))
+    }
+}
+
+/// One line per row, each cell printed without separator.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\r\n456\r\n", "a digit", |char| char.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn nominal_parse_grid() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn invalid_parse_grid() {
+        let error = Grid::parse("12\n3x\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "x", "a digit"));
+
+        let error = Grid::parse("12\n3\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "3", "a row of 2 cells"));
+        // Blank lines are skipped but still counted
+        let error = Grid::parse("\n12\n\n3x\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(4, 2, "x", "a digit"));
+    }
+
+    #[test]
+    fn nominal_neighbours_and_rays() {
+        let grid = digits();
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8((1, 0)).count(), 5);
+        assert_eq!(
+            grid.ray((0, 1), (1, 0)).collect::<Vec<_>>(),
+            [(1, 1), (2, 1)]
+        );
+        assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn nominal_transpose_and_expand() {
+        let mut grid = digits().transpose();
+        assert_eq!(grid.to_string(), "14\n25\n36\n");
+
+        grid.expand(1, 0, 0, 1, 0);
+        assert_eq!(grid.to_string(), "014\n025\n036\n000\n");
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![]]), None);
+    }
+}
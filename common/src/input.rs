@@ -0,0 +1,214 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub static INPUT_DIR: &str = "./input";
+
+/// Where the puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum InputSource {
+    /// `input.txt` in the input directory
+    #[default]
+    Puzzle,
+    /// `example-N.txt` in the input directory
+    Example(u32),
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    /// File backing this source, resolved against `input_dir` when needed.
+    pub fn path(&self, input_dir: &Path) -> Option<PathBuf> {
+        match self {
+            InputSource::Puzzle => Some(input_dir.join("input.txt")),
+            InputSource::Example(number) => Some(input_dir.join(format!("example-{}.txt", number))),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin | InputSource::Text(_) => None,
+        }
+    }
+
+    /// Name of the input in the answers file, e.g. `input` or `example-1`.
+    pub fn name(&self) -> Option<String> {
+        match self {
+            InputSource::Puzzle => Some("input".to_string()),
+            InputSource::Example(number) => Some(format!("example-{}", number)),
+            _ => None,
+        }
+    }
+
+    /// Puzzle and example inputs present in `input_dir`, examples sorted by number.
+    pub fn available(input_dir: &Path) -> Vec<InputSource> {
+        let mut examples: Vec<u32> = fs::read_dir(input_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                file_name
+                    .strip_prefix("example-")?
+                    .strip_suffix(".txt")?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        examples.sort_unstable();
+
+        let mut sources = Vec::new();
+
+        if InputSource::Puzzle.path(input_dir).unwrap().is_file() {
+            sources.push(InputSource::Puzzle);
+        }
+        sources.extend(examples.into_iter().map(InputSource::Example));
+
+        sources
+    }
+}
+
+/// Parse a source given on the command line: `-` is stdin, anything else a path.
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::Path(PathBuf::from(value))),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Could not read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Line telling where the input of `source` comes from, followed by a blank line.
+///
+/// Empty for inline text, which has no origin worth showing.
+pub fn input_banner(source: &InputSource, input_dir: &Path) -> String {
+    match source.path(input_dir) {
+        Some(file_path) => {
+            let file_path = fs::canonicalize(&file_path).unwrap_or(file_path);
+            format!("Input File: {:?}\n\n", file_path)
+        }
+        None => match source {
+            InputSource::Stdin => "Input File: <stdin>\n\n".to_string(),
+            _ => String::new(),
+        },
+    }
+}
+
+/// Read the puzzle input from `source`.
+///
+/// Relative sources (`Puzzle`, `Example`) are looked up in `input_dir`. When `banner`
+/// is set, the origin of the input is printed before it is returned.
+pub fn read_input(
+    source: &InputSource,
+    input_dir: &Path,
+    banner: bool,
+) -> Result<String, InputError> {
+    let file_content = match source.path(input_dir) {
+        Some(file_path) => {
+            let file_content = fs::read_to_string(&file_path).map_err(|error| InputError {
+                source: format!("{:?}", file_path),
+                error,
+            })?;
+
+            if banner {
+                print!("{}", input_banner(source, input_dir));
+            }
+
+            file_content
+        }
+        None => match source {
+            InputSource::Text(text) => text.clone(),
+            _ => {
+                let mut file_content = String::new();
+                io::stdin()
+                    .read_to_string(&mut file_content)
+                    .map_err(|error| InputError {
+                        source: "stdin".to_string(),
+                        error,
+                    })?;
+
+                if banner {
+                    print!("{}", input_banner(source, input_dir));
+                }
+
+                file_content
+            }
+        },
+    };
+
+    Ok(file_content)
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_read_input_text() {
+        let source = InputSource::Text("1\n2\n".to_string());
+        let result = read_input(&source, Path::new(INPUT_DIR), false).unwrap();
+        assert_eq!(result, "1\n2\n");
+    }
+
+    #[test]
+    fn nominal_example_path() {
+        let source = InputSource::Example(2);
+        let result = source.path(Path::new("day-9/input"));
+        assert_eq!(result, Some(PathBuf::from("day-9/input/example-2.txt")));
+    }
+
+    #[test]
+    fn nominal_input_name() {
+        assert_eq!(InputSource::Puzzle.name(), Some("input".to_string()));
+        assert_eq!(
+            InputSource::Example(2).name(),
+            Some("example-2".to_string())
+        );
+        assert_eq!(InputSource::Stdin.name(), None);
+    }
+
+    #[test]
+    fn nominal_input_banner() {
+        let source = InputSource::Path(PathBuf::from("./missing/input.txt"));
+        let banner = input_banner(&source, Path::new(INPUT_DIR));
+        assert_eq!(banner, "Input File: \"./missing/input.txt\"\n\n");
+
+        let source = InputSource::Text("1\n".to_string());
+        assert_eq!(input_banner(&source, Path::new(INPUT_DIR)), "");
+    }
+
+    #[test]
+    fn missing_input_file() {
+        let source = InputSource::Path(PathBuf::from("./missing/input.txt"));
+        let result = read_input(&source, Path::new(INPUT_DIR), false).unwrap_err();
+        assert_eq!(result.error.kind(), io::ErrorKind::NotFound);
+        assert!(result.to_string().contains("missing/input.txt"));
+    }
+}
@@ -1,43 +1,114 @@
-use std::fs;
-use std::path::PathBuf;
+mod answer;
+mod answers;
+mod bench;
+mod config;
+mod examples;
+pub mod export;
+pub mod geom;
+mod grid;
+mod input;
+mod output;
+pub mod parse;
+mod properties;
+mod rng;
+mod solution;
+pub mod viz;
 
-pub static INPUT_FILE: &str = "./input/input.txt";
+use std::path::Path;
+use std::process;
 
-pub fn read_input_file() -> String {
-    let file_path = PathBuf::from(INPUT_FILE);
-    println!(
-        "Input File: {:?}\n",
-        fs::canonicalize(&file_path).expect("Error")
-    );
-    fs::read_to_string(file_path).expect("Should have been able to read the file")
-}
+pub use crate::answer::Answer;
+pub use crate::answers::{AnswerRegistry, AnswersError, KnownAnswers, Verdict, ANSWERS_FILE};
+pub use crate::bench::{format_bytes, format_duration, timed, MemoryStats, Stats};
+pub use crate::config::{Config, ConfigError, CONFIG_FILE};
+pub use crate::examples::check_example;
+pub use crate::grid::Grid;
+pub use crate::input::{input_banner, read_input, InputError, InputSource, INPUT_DIR};
+pub use crate::output::{format_results, OutputFormat, PartResult};
+pub use crate::parse::ParseError;
+pub use crate::properties::{check_alternatives, check_generated, check_naive};
+pub use crate::rng::Rng;
+pub use crate::solution::{AnySolution, Implementation, Solution, DEFAULT_IMPLEMENTATION};
 
 #[derive(Debug, PartialEq)]
 pub struct Puzzle {
     question: String,
-    answer: String,
+    answer: Answer,
 }
 
-pub fn get_puzzle_part(question: String, answer: String) -> Puzzle {
+pub fn get_puzzle_part(question: String, answer: Answer) -> Puzzle {
     Puzzle { question, answer }
 }
 
 pub fn print_puzzle(puzzle_name: String, puzzle: [Puzzle; 2]) {
-    let tab = " ".repeat(4);
-    let part = ["One", "Two"];
-
     println!("{}", puzzle_name);
     println!();
 
-    for i in 0..2 {
-        println!("{}Part {}:", tab.repeat(1), part[i]);
-        println!("{}{}{}", tab.repeat(2), "Question: ", puzzle[i].question);
-        println!("{}{}{}", tab.repeat(2), "  Answer: ", puzzle[i].answer);
-        println!();
+    for (i, puzzle_part) in puzzle.iter().enumerate() {
+        print_puzzle_part(i + 1, puzzle_part);
+    }
+}
+
+pub fn print_puzzle_part(part: usize, puzzle: &Puzzle) {
+    print!("{}", format_puzzle_part(part, puzzle));
+}
+
+/// The lines printed by [`print_puzzle_part`], including the trailing blank line.
+pub fn format_puzzle_part(part: usize, puzzle: &Puzzle) -> String {
+    let tab = " ".repeat(4);
+    let part_name = ["One", "Two"];
+    let mut text = String::new();
+
+    text += &format!("{}Part {}:\n", tab, part_name[part - 1]);
+    text += &format!("{}Question: {}\n", tab.repeat(2), puzzle.question);
+
+    // Multi-line answers are aligned under the first line
+    for (i, line) in puzzle.answer.lines().iter().enumerate() {
+        if i == 0 {
+            text += &format!("{}  Answer: {}\n", tab.repeat(2), line);
+        } else {
+            text += &format!("{}{}\n", " ".repeat(18), line);
+        }
     }
+    text += "\n";
+
+    text
+}
+
+/// Solve both parts of a day on `./input/input.txt`, with the settings of `./config.toml`,
+/// and print them.
+pub fn run<S: Solution>() {
+    let file_content = match read_input(&InputSource::Puzzle, Path::new(INPUT_DIR), true) {
+        Ok(file_content) => file_content,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let mut input = match S::parse(&file_content) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error.snippet(&file_content));
+            process::exit(1);
+        }
+    };
+    if let Err(error) =
+        Config::load(Path::new(".")).and_then(|config| S::configure(&mut input, &config))
+    {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+
+    print_puzzle(
+        S::NAME.to_string(),
+        [
+            get_puzzle_part(S::QUESTIONS[0].to_string(), S::part_one(&input)),
+            get_puzzle_part(S::QUESTIONS[1].to_string(), S::part_two(&input)),
+        ],
+    );
 }
 
-/// ================ Unit Tests ================
+// ================ Unit Tests ================
 
 #[cfg(test)]
 mod tests {
@@ -47,9 +118,9 @@ mod tests {
     fn nominal_get_puzzle_part() {
         let puzzle = Puzzle {
             question: "test".to_string(),
-            answer: 10.to_string(),
+            answer: Answer::Integer(10),
         };
-        let result = get_puzzle_part("test".to_string(), 10.to_string());
+        let result = get_puzzle_part("test".to_string(), Answer::from(10));
         assert_eq!(result, puzzle);
     }
 }
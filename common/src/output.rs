@@ -0,0 +1,299 @@
+use crate::bench::{format_bytes, format_duration, MemoryStats};
+use crate::{format_puzzle_part, get_puzzle_part, Answer};
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How results are printed by the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// The indented `Question:` / `Answer:` blocks of [`print_puzzle`](crate::print_puzzle)
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            _ => Err(format!(
+                "Unknown output format `{}`, expected text, json, csv or markdown",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Markdown => "markdown",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Answer to one part of a day, with what it took to solve it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartResult {
+    pub day: u32,
+    pub name: String,
+    pub part: usize,
+    pub question: String,
+    pub answer: Answer,
+    /// Time spent solving the part, parsing excluded
+    #[serde(rename = "time_ns", serialize_with = "serialize_nanos")]
+    pub time: Duration,
+    /// Answer taken from the runner's cache, so not solved and timed this run
+    pub cached: bool,
+    /// Heap use while solving the part, only counted with `--alloc`
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+fn serialize_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+/// Quote a CSV field when it holds a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Markdown table cell, one code span per answer line so grids keep their shape.
+fn markdown_answer(answer: &Answer) -> String {
+    answer
+        .lines()
+        .iter()
+        .map(|line| format!("`{}`", line.replace('|', "\\|")))
+        .collect::<Vec<String>>()
+        .join("<br>")
+}
+
+/// Render `results` in `format`, ready to be printed as is. Tables get memory columns
+/// when any result has its heap use.
+pub fn format_results(results: &[PartResult], format: OutputFormat) -> String {
+    let mut text = String::new();
+    let memory = results.iter().any(|result| result.memory.is_some());
+
+    match format {
+        OutputFormat::Text => {
+            for (i, result) in results.iter().enumerate() {
+                // Day name once, above its first part
+                if i == 0 || results[i - 1].day != result.day {
+                    text += &format!("{}\n\n", result.name);
+                }
+
+                let puzzle = get_puzzle_part(result.question.clone(), result.answer.clone());
+                text += &format_puzzle_part(result.part, &puzzle);
+
+                // Under the answer, before the blank line closing the part
+                if let Some(memory) = result.memory {
+                    text.pop();
+                    text += &format!(
+                        "{}  Memory: {} allocations, {} allocated, {} peak\n\n",
+                        " ".repeat(8),
+                        memory.allocations,
+                        format_bytes(memory.allocated),
+                        format_bytes(memory.peak)
+                    );
+                }
+            }
+        }
+        OutputFormat::Json => {
+            text = serde_json::to_string_pretty(results).expect("Results should serialize");
+            text += "\n";
+        }
+        OutputFormat::Csv => {
+            text += "day,part,question,answer,time_ns,cached";
+            if memory {
+                text += ",allocations,allocated_bytes,peak_bytes";
+            }
+            text += "\n";
+
+            for result in results {
+                text += &format!(
+                    "{},{},{},{},{},{}",
+                    result.day,
+                    result.part,
+                    csv_field(&result.question),
+                    csv_field(&result.answer.to_string()),
+                    result.time.as_nanos(),
+                    result.cached
+                );
+                if memory {
+                    let stats = result.memory.unwrap_or_default();
+                    text += &format!(",{},{},{}", stats.allocations, stats.allocated, stats.peak);
+                }
+                text += "\n";
+            }
+        }
+        OutputFormat::Markdown => {
+            text += "| Day | Part | Question | Answer | Time |";
+            if memory {
+                text += " Allocations | Allocated | Peak |";
+            }
+            text += "\n| ---: | ---: | --- | --- | ---: |";
+            if memory {
+                text += " ---: | ---: | ---: |";
+            }
+            text += "\n";
+
+            for result in results {
+                text += &format!(
+                    "| {} | {} | {} | {} | {} |",
+                    result.day,
+                    result.part,
+                    result.question.replace('|', "\\|"),
+                    markdown_answer(&result.answer),
+                    if result.cached {
+                        "cached".to_string()
+                    } else {
+                        format_duration(result.time)
+                    }
+                );
+                if memory {
+                    let stats = result.memory.unwrap_or_default();
+                    text += &format!(
+                        " {} | {} | {} |",
+                        stats.allocations,
+                        format_bytes(stats.allocated),
+                        format_bytes(stats.peak)
+                    );
+                }
+                text += "\n";
+            }
+        }
+    }
+
+    text
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: 5,
+                name: "--- Day 5: Supply Stacks ---".to_string(),
+                part: 1,
+                question: "What crate, ends up on top?".to_string(),
+                answer: Answer::from("CMZ"),
+                time: Duration::from_nanos(1500),
+                cached: false,
+                memory: None,
+            },
+            PartResult {
+                day: 10,
+                name: "--- Day 10: Cathode-Ray Tube ---".to_string(),
+                part: 2,
+                question: "What letters appear?".to_string(),
+                answer: Answer::Grid(vec!["#.".to_string(), ".#".to_string()]),
+                time: Duration::ZERO,
+                cached: true,
+                memory: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn nominal_output_format_from_str() {
+        assert_eq!("JSON".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("md".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn nominal_format_json() {
+        let json = format_results(&results(), OutputFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value[0]["answer"], "CMZ");
+        assert_eq!(value[0]["time_ns"], 1500);
+        assert_eq!(value[0]["cached"], false);
+        assert_eq!(value[1]["cached"], true);
+        assert_eq!(value[1]["answer"][1], ".#");
+    }
+
+    #[test]
+    fn nominal_format_csv() {
+        let csv = format_results(&results(), OutputFormat::Csv);
+
+        assert_eq!(
+            csv,
+            "day,part,question,answer,time_ns,cached\n\
+            5,1,\"What crate, ends up on top?\",CMZ,1500,false\n\
+            10,2,What letters appear?,\"#.\n.#\",0,true\n"
+        );
+    }
+
+    #[test]
+    fn nominal_format_markdown() {
+        let markdown = format_results(&results(), OutputFormat::Markdown);
+        let rows: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[3],
+            "| 10 | 2 | What letters appear? | `#.`<br>`.#` | cached |"
+        );
+    }
+
+    #[test]
+    fn nominal_format_memory() {
+        let mut results = results();
+        results[0].memory = Some(MemoryStats {
+            allocations: 3,
+            allocated: 2048,
+            peak: 1024,
+        });
+
+        let json = format_results(&results, OutputFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["allocated_bytes"], 2048);
+        assert_eq!(value[1].get("peak_bytes"), None);
+
+        let csv = format_results(&results, OutputFormat::Csv);
+        assert!(csv.starts_with(
+            "day,part,question,answer,time_ns,cached,allocations,allocated_bytes,peak_bytes\n"
+        ));
+        assert!(csv.contains(",CMZ,1500,false,3,2048,1024\n"));
+
+        let markdown = format_results(&results, OutputFormat::Markdown);
+        assert!(markdown.contains("| 1.50 µs | 3 | 2.00 KiB | 1.00 KiB |\n"));
+
+        let text = format_results(&results[..1], OutputFormat::Text);
+        assert!(text.ends_with(
+            "          Answer: CMZ\n          Memory: 3 allocations, 2.00 KiB allocated, 1.00 KiB peak\n\n"
+        ));
+    }
+
+    #[test]
+    fn nominal_format_text() {
+        let text = format_results(&results()[..1], OutputFormat::Text);
+
+        assert!(text.starts_with("--- Day 5: Supply Stacks ---\n\n    Part One:\n"));
+        assert!(text.ends_with("          Answer: CMZ\n\n"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Input parsing helpers that behave the same on `\n` and `\r\n` line endings.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error found while parsing a puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+    /// Offending text, empty at the end of a line or of the input
+    pub text: String,
+    /// What the parser expected instead, e.g. "a number"
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error pointing at `token`, which should be a slice of `input`.
+    ///
+    /// Everything returned by [`lines`], [`blocks`] or `str::split` on the input is
+    /// such a slice, so the position is found without tracking line numbers by hand.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_of(input, token);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            token,
+            expected,
+        )
+    }
+
+    /// The error followed by the offending line of `input`, with the text underlined.
+    pub fn snippet(&self, input: &str) -> String {
+        let source_line = lines(input).nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let caret = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            self.line,
+            source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            caret
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Parse error at line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Byte offset of `token` in `input`, falling back to a search when it is not a slice.
+fn offset_of(input: &str, token: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+
+    if position >= start && position + token.len() <= start + input.len() {
+        position - start
+    } else {
+        input.find(token).unwrap_or(input.len())
+    }
+}
+
+/// Parse `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+/// `count` followed by `noun`, with an `s` unless there is one, e.g. `2 words`.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// The `N` whitespace separated words of `line`, a slice of `input`.
+pub fn words<'a, const N: usize>(input: &str, line: &'a str) -> Result<[&'a str; N], ParseError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+
+    words
+        .try_into()
+        .map_err(|_| ParseError::at(input, line, plural(N, "word")))
+}
+
+/// The `N` integers of `line`, a slice of `input`, see [`ints`], as `T`. An integer `T`
+/// cannot hold, such as a negative one for an unsigned `T`, is reported instead of skipped
+/// or wrapped.
+pub fn ints_exact<T: FromStr, const N: usize>(
+    input: &str,
+    line: &str,
+) -> Result<[T; N], ParseError> {
+    let values: Vec<T> = int_tokens(line)
+        .map(|token| {
+            token.parse::<T>().map_err(|_| {
+                ParseError::at(
+                    input,
+                    token,
+                    format!("an integer fitting in {}", std::any::type_name::<T>()),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    values
+        .try_into()
+        .map_err(|_| ParseError::at(input, line, plural(N, "integer")))
+}
+
+/// Lines of `text` without the line ending and trailing whitespace.
+///
+/// Leading whitespace is kept, since some inputs (e.g. crate drawings) depend on it.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(|line| line.trim_end())
+}
+
+/// Blocks of `text` separated by one or more blank lines.
+///
+/// Each block is a slice of the original text, from the start of its first line to
+/// the end of its last line, so it can be iterated again with [`lines`].
+pub fn blocks(text: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut block_start = None;
+    let mut block_end = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = block_start.take() {
+                blocks.push(&text[start..block_end]);
+            }
+        } else {
+            block_start.get_or_insert(offset);
+            block_end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = block_start {
+        blocks.push(&text[start..block_end]);
+    }
+
+    blocks
+}
+
+/// All the integers found in `text`, in order, skipping those too large for an `i64`.
+///
+/// A `-` counts as a sign only when it is not preceded by a letter or digit, so
+/// ranges like `2-4` give `2, 4` while `addx -3` gives `-3`.
+pub fn ints(text: &str) -> impl Iterator<Item = i64> + '_ {
+    int_tokens(text).filter_map(|token| token.parse().ok())
+}
+
+/// Slices of `text` holding the integers of [`ints`], sign included.
+fn int_tokens(text: &str) -> impl Iterator<Item = &str> + '_ {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() {
+            let start = index;
+            let negative = bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+
+            if negative || bytes[index].is_ascii_digit() {
+                index += 1;
+                while index < bytes.len() && bytes[index].is_ascii_digit() {
+                    index += 1;
+                }
+                return Some(&text[start..index]);
+            }
+
+            index += 1;
+        }
+
+        None
+    })
+}
+
+/// Rectangular-ish grid of the characters of `text`, one row per line.
+pub fn char_grid(text: &str) -> Vec<Vec<char>> {
+    lines(text)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect()
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_blocks() {
+        let lf = "1000\n2000\n\n3000\n\n\n4000\n";
+        let crlf = lf.replace('\n', "\r\n");
+
+        assert_eq!(blocks(lf), vec!["1000\n2000", "3000", "4000"]);
+        assert_eq!(blocks(&crlf), vec!["1000\r\n2000", "3000", "4000"]);
+        assert_eq!(
+            blocks(&crlf)
+                .iter()
+                .map(|x| lines(x).count())
+                .sum::<usize>(),
+            4
+        );
+    }
+
+    #[test]
+    fn nominal_lines() {
+        let result: Vec<&str> = lines("    [D]    \r\n[N] [C]\r\n").collect();
+        assert_eq!(result, vec!["    [D]", "[N] [C]"]);
+    }
+
+    #[test]
+    fn nominal_ints() {
+        assert_eq!(ints("2-4,6-8").collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+        assert_eq!(ints("addx -11").collect::<Vec<_>>(), vec![-11]);
+        assert_eq!(
+            ints("move 1 from 2 to 1").collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+        assert_eq!(ints("noop").count(), 0);
+        assert_eq!(
+            ints("1 99999999999999999999 3").collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn nominal_parse_error_position() {
+        let input = "R 4\r\nU x\r\n";
+        let token = input
+            .lines()
+            .nth(1)
+            .unwrap()
+            .split_whitespace()
+            .nth(1)
+            .unwrap();
+        let error = number::<usize>(input, token).unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 3, "x", "a number"));
+        assert_eq!(
+            error.snippet(input),
+            "Parse error at line 2, column 3: expected a number, found `x`\n  |\n2 | U x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn wrong_count_words_and_ints() {
+        let input = "noop\naddx 3 4\n";
+        let line = input.lines().nth(1).unwrap();
+
+        assert_eq!(words::<2>(input, "noop").unwrap_err().line, 1);
+        assert_eq!(words::<2>(input, &line[..6]).unwrap(), ["addx", "3"]);
+        assert_eq!(
+            ints_exact::<i64, 1>(input, line).unwrap_err().expected,
+            "1 integer"
+        );
+        assert_eq!(words::<4>(input, line).unwrap_err().expected, "4 words");
+
+        let input = "1 99999999999999999999\n";
+        let error = ints_exact::<i64, 2>(input, input.trim_end()).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 3, "99999999999999999999", "an integer fitting in i64")
+        );
+        assert_eq!(ints_exact::<i64, 2>(input, line).unwrap(), [3, 4]);
+
+        let input = "move -1 from 2 to 1\n";
+        let error = ints_exact::<u32, 3>(input, input.trim_end()).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 6, "-1", "an integer fitting in u32")
+        );
+    }
+
+    #[test]
+    fn nominal_char_grid() {
+        let result = char_grid("30\r\n25\r\n");
+        assert_eq!(result, vec![vec!['3', '0'], vec!['2', '5']]);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{Answer, Rng, Solution};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Run `check` on `cases` random inputs of a day, given as text and parsed.
+///
+/// Inputs come from [`Solution::generate`] with seeds `0..cases` and sizes cycling
+/// from 1 to `max_size`. Panics on the first input that does not parse or that `check`
+/// panics on, telling which `aoc generate` command prints it again.
+pub fn check_generated<S: Solution>(
+    cases: u64,
+    max_size: usize,
+    mut check: impl FnMut(&str, &S::Input),
+) {
+    for seed in 0..cases {
+        let size = 1 + seed as usize % max_size.max(1);
+        let command = format!(
+            "aoc generate --day {} --size {} --seed {}",
+            S::DAY,
+            size,
+            seed
+        );
+
+        let file_content = S::generate(size, &mut Rng::new(seed))
+            .unwrap_or_else(|| panic!("Day {} should have an input generator", S::DAY));
+        let input = S::parse(&file_content).unwrap_or_else(|error| {
+            panic!(
+                "{}\nInput printed by `{}`",
+                error.snippet(&file_content),
+                command
+            )
+        });
+
+        if let Err(cause) = panic::catch_unwind(AssertUnwindSafe(|| check(&file_content, &input))) {
+            eprintln!(
+                "Day {}: check failed on the input printed by `{}`",
+                S::DAY,
+                command
+            );
+            panic::resume_unwind(cause);
+        }
+    }
+}
+
+/// Compare both parts of a day with `naive`, a straightforward reference solving the
+/// text of the input, on random inputs as in [`check_generated`].
+pub fn check_naive<S: Solution>(cases: u64, max_size: usize, naive: impl Fn(&str) -> [Answer; 2]) {
+    check_generated::<S>(cases, max_size, |file_content, input| {
+        assert_eq!(
+            [S::part_one(input), S::part_two(input)],
+            naive(file_content),
+            "Day {} should agree with its naive reference",
+            S::DAY
+        );
+    });
+}
+
+/// Compare every [`Solution::alternatives`] of a day with the default implementation
+/// of its part, on random inputs as in [`check_generated`].
+pub fn check_alternatives<S: Solution>(cases: u64, max_size: usize) {
+    check_generated::<S>(cases, max_size, |_, input| {
+        for alternative in S::alternatives() {
+            let expected = match alternative.part {
+                1 => S::part_one(input),
+                _ => S::part_two(input),
+            };
+
+            assert_eq!(
+                (alternative.solve)(input),
+                expected,
+                "Day {} part {} `{}` should agree with the default implementation",
+                S::DAY,
+                alternative.part,
+                alternative.name
+            );
+        }
+    });
+}
@@ -0,0 +1,113 @@
+//! Small seeded random number generator, so generated inputs can be reproduced from
+//! their seed on any machine.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64 generator: fast, tiny state and good enough for puzzle inputs, not for
+/// anything cryptographic.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `0..bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Bound should not be 0");
+
+        // Rejecting the last partial run of `bound` values keeps every number equally likely
+        let bound = bound as u64;
+        let limit = u64::MAX - u64::MAX % bound;
+
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    /// Uniform number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(
+            start <= end,
+            "Range {}..={} should not be empty",
+            start,
+            end
+        );
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64() as usize,
+        }
+    }
+
+    /// `true` with the given `probability`, between 0 and 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // The 53 high bits fill the mantissa of a number in [0, 1)
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Uniformly picked item of `items`, which should not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffle `items` in place, every order being equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_rng() {
+        // First outputs of the reference SplitMix64 seeded with 0
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+        let mut rng = Rng::new(2022);
+        let values: Vec<usize> = (0..1000).map(|_| rng.range(3..=5)).collect();
+        assert!(values.iter().all(|value| (3..=5).contains(value)));
+        assert!((3..=5).all(|value| values.contains(&value)));
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nominal_rng_reproducible() {
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert!(!Rng::new(1).chance(0.0));
+        assert!(Rng::new(1).chance(1.0));
+    }
+}
@@ -0,0 +1,254 @@
+use crate::viz::Render;
+use crate::{Answer, Config, ConfigError, ParseError, Rng};
+use std::any::Any;
+
+/// Name of the implementation given by [`Solution::part_one`] and [`Solution::part_two`].
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+/// Another way of solving a part, registered by [`Solution::alternatives`].
+pub struct Implementation<I> {
+    pub name: &'static str,
+    pub part: usize,
+    pub solve: fn(&I) -> Answer,
+}
+
+/// A puzzle solution for one day.
+///
+/// The input is parsed once by [`Solution::parse`] and then shared by both parts, possibly
+/// solved at the same time on different threads.
+pub trait Solution {
+    type Input: Send + Sync + 'static;
+
+    const DAY: u32;
+    const NAME: &'static str;
+    const QUESTIONS: [&'static str; 2];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Answer;
+
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// Apply the tunable constants of `config` to a parsed input, which otherwise solves
+    /// the puzzle as stated; days without any reject every setting.
+    fn configure(_input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        if config.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::new(format!(
+                "Day {} has no settings",
+                Self::DAY
+            )))
+        }
+    }
+
+    /// Simulation behind `part` (1 or 2), played by `--visualize`; most days have none.
+    fn visualize(_input: &Self::Input, _part: usize) -> Option<Box<dyn Render + '_>> {
+        None
+    }
+
+    /// Random valid input of about `size` records (lines, groups, rows...), printed by
+    /// `aoc generate`; `None` for days without a generator.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+
+    /// Other implementations of the parts, picked by `--impl` and compared with the
+    /// default one by `--cross-check`; most days have none.
+    fn alternatives() -> Vec<Implementation<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// Object safe view of a [`Solution`], so days can be stored and run side by side.
+pub trait AnySolution: Send + Sync {
+    fn day(&self) -> u32;
+
+    fn name(&self) -> &'static str;
+
+    fn questions(&self) -> [&'static str; 2];
+
+    fn parse_any(&self, file_content: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError>;
+
+    /// [`Solution::configure`] on an input returned by [`AnySolution::parse_any`].
+    fn configure_any(&self, input: &mut dyn Any, config: &Config) -> Result<(), ConfigError>;
+
+    /// Solve `part` (1 or 2) on an input returned by [`AnySolution::parse_any`].
+    fn solve_any(&self, input: &dyn Any, part: usize) -> Answer;
+
+    /// Names of the implementations of `part`, [`DEFAULT_IMPLEMENTATION`] first.
+    fn implementations(&self, part: usize) -> Vec<&'static str>;
+
+    /// Solve `part` with the implementation called `name`, `None` if there is none.
+    fn solve_with(&self, input: &dyn Any, part: usize, name: &str) -> Option<Answer>;
+
+    /// [`Solution::visualize`] on an input returned by [`AnySolution::parse_any`].
+    fn visualize_any<'a>(&self, input: &'a dyn Any, part: usize) -> Option<Box<dyn Render + 'a>>;
+
+    fn generate_any(&self, size: usize, rng: &mut Rng) -> Option<String>;
+}
+
+impl<S: Solution + Send + Sync> AnySolution for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn questions(&self) -> [&'static str; 2] {
+        S::QUESTIONS
+    }
+
+    fn parse_any(&self, file_content: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError> {
+        Ok(Box::new(S::parse(file_content)?))
+    }
+
+    fn configure_any(&self, input: &mut dyn Any, config: &Config) -> Result<(), ConfigError> {
+        let input = input
+            .downcast_mut::<S::Input>()
+            .expect("Input should have been parsed by the same solution");
+
+        S::configure(input, config)
+    }
+
+    fn solve_any(&self, input: &dyn Any, part: usize) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should have been parsed by the same solution");
+
+        match part {
+            1 => S::part_one(input),
+            2 => S::part_two(input),
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+
+    fn implementations(&self, part: usize) -> Vec<&'static str> {
+        let alternatives = S::alternatives()
+            .into_iter()
+            .filter(|implementation| implementation.part == part)
+            .map(|implementation| implementation.name);
+
+        std::iter::once(DEFAULT_IMPLEMENTATION)
+            .chain(alternatives)
+            .collect()
+    }
+
+    fn solve_with(&self, input: &dyn Any, part: usize, name: &str) -> Option<Answer> {
+        if name == DEFAULT_IMPLEMENTATION {
+            return Some(self.solve_any(input, part));
+        }
+
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should have been parsed by the same solution");
+
+        S::alternatives()
+            .into_iter()
+            .find(|implementation| implementation.part == part && implementation.name == name)
+            .map(|implementation| (implementation.solve)(input))
+    }
+
+    fn visualize_any<'a>(&self, input: &'a dyn Any, part: usize) -> Option<Box<dyn Render + 'a>> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should have been parsed by the same solution");
+
+        S::visualize(input, part)
+    }
+
+    fn generate_any(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        S::generate(size, rng)
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        const DAY: u32 = 0;
+        const NAME: &'static str = "--- Day 0: Sum ---";
+        const QUESTIONS: [&'static str; 2] = ["Sum?", "Max?"];
+
+        fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+            crate::parse::lines(file_content)
+                .map(|line| crate::parse::number(file_content, line))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part_two(input: &Self::Input) -> Answer {
+            (*input.iter().max().unwrap()).into()
+        }
+
+        fn alternatives() -> Vec<Implementation<Self::Input>> {
+            vec![Implementation {
+                name: "reversed",
+                part: 1,
+                solve: |input| input.iter().rev().sum::<u32>().into(),
+            }]
+        }
+    }
+
+    #[test]
+    fn nominal_any_solution() {
+        let solution: Box<dyn AnySolution> = Box::new(Sum);
+        let input = solution.parse_any("1\n5\n3").unwrap();
+
+        assert_eq!(solution.day(), 0);
+        assert_eq!(solution.solve_any(input.as_ref(), 1), Answer::Integer(9));
+        assert_eq!(solution.solve_any(input.as_ref(), 2), Answer::Integer(5));
+    }
+
+    #[test]
+    fn nominal_implementations() {
+        let solution: Box<dyn AnySolution> = Box::new(Sum);
+        let input = solution.parse_any("1\n5\n3").unwrap();
+
+        assert_eq!(solution.implementations(1), ["default", "reversed"]);
+        assert_eq!(solution.implementations(2), ["default"]);
+        assert_eq!(
+            solution.solve_with(input.as_ref(), 1, "reversed"),
+            Some(Answer::Integer(9))
+        );
+        assert_eq!(
+            solution.solve_with(input.as_ref(), 2, "default"),
+            Some(Answer::Integer(5))
+        );
+        assert_eq!(solution.solve_with(input.as_ref(), 2, "reversed"), None);
+    }
+
+    #[test]
+    fn invalid_any_solution() {
+        let solution: Box<dyn AnySolution> = Box::new(Sum);
+        let error = solution.parse_any("1\nfive\n3").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 1, "five", "a number"));
+    }
+
+    #[test]
+    fn invalid_configure_without_settings() {
+        let solution: Box<dyn AnySolution> = Box::new(Sum);
+        let mut input = solution.parse_any("1\n5\n3").unwrap();
+        let mut config = Config::default();
+
+        assert_eq!(solution.configure_any(input.as_mut(), &config), Ok(()));
+        config.set("rounds=3").unwrap();
+        assert_eq!(
+            solution.configure_any(input.as_mut(), &config),
+            Err(ConfigError::new("Day 0 has no settings"))
+        );
+    }
+}
@@ -0,0 +1,347 @@
+//! Terminal playback of the simulations behind some puzzles.
+//!
+//! A day exposes its simulation through [`Solution::visualize`](crate::Solution::visualize)
+//! as a [`Render`], which hands out [`Frame`]s one at a time, and a [`Player`] draws them
+//! with ANSI escape codes.
+
+use crate::Grid;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Colours a cell can be drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+    /// Swapped foreground and background, to make a cell stand out
+    Highlight,
+}
+
+impl Colour {
+    fn sgr(self) -> &'static str {
+        match self {
+            Colour::Red => "31",
+            Colour::Green => "32",
+            Colour::Yellow => "33",
+            Colour::Blue => "34",
+            Colour::Magenta => "35",
+            Colour::Cyan => "36",
+            Colour::Grey => "90",
+            Colour::Highlight => "7",
+        }
+    }
+
+    /// `text` drawn in this colour on an ANSI terminal.
+    pub fn paint(self, text: &str) -> String {
+        format!("\x1b[{}m{}\x1b[0m", self.sgr(), text)
+    }
+}
+
+/// One character of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub char: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn new(char: char, colour: Colour) -> Cell {
+        Cell {
+            char,
+            colour: Some(colour),
+        }
+    }
+
+    pub fn plain(char: char) -> Cell {
+        Cell { char, colour: None }
+    }
+}
+
+/// Picture of a simulation at one step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    /// Text printed under the picture, e.g. the step number
+    pub caption: String,
+    /// Position kept in view when the picture is larger than the terminal
+    pub focus: Option<(usize, usize)>,
+}
+
+/// Start of the `size` long window of a `len` long axis keeping `focus` near its middle.
+fn window_start(len: usize, size: usize, focus: Option<usize>) -> usize {
+    match focus {
+        Some(focus) if len > size => focus.saturating_sub(size / 2).min(len - size),
+        _ => 0,
+    }
+}
+
+impl Frame {
+    /// Part of the frame fitting in `viewport` (width, height) cells, around its focus.
+    pub fn crop(&self, viewport: (usize, usize)) -> Grid<Cell> {
+        let width = self.cells.width().min(viewport.0);
+        let height = self.cells.height().min(viewport.1);
+        let left = window_start(self.cells.width(), width, self.focus.map(|focus| focus.0));
+        let top = window_start(self.cells.height(), height, self.focus.map(|focus| focus.1));
+
+        let rows = (top..top + height)
+            .map(|y| self.cells.row(y)[left..left + width].to_vec())
+            .collect();
+
+        Grid::from_rows(rows).expect("Cropped rows should have the same width")
+    }
+
+    /// Text of the frame cropped to `viewport` (width, height) cells, lines ended by
+    /// `\r\n` so it also prints right in raw mode.
+    pub fn to_ansi(&self, viewport: (usize, usize), colour: bool) -> String {
+        let mut text = String::new();
+
+        for row in self.crop(viewport).rows() {
+            let mut current = None;
+
+            for cell in row {
+                if colour && cell.colour != current {
+                    text += "\x1b[0m";
+                    if let Some(colour) = cell.colour {
+                        text += &format!("\x1b[{}m", colour.sgr());
+                    }
+                    current = cell.colour;
+                }
+                text.push(cell.char);
+            }
+
+            if colour && current.is_some() {
+                text += "\x1b[0m";
+            }
+            text += "\r\n";
+        }
+
+        text + &self.caption + "\r\n"
+    }
+}
+
+/// A simulation that can be watched step by step.
+pub trait Render {
+    /// Picture of the next step, `None` once the simulation is over.
+    fn next_frame(&mut self) -> Option<Frame>;
+}
+
+/// Any iterator of frames plays as is, e.g. `std::iter::once(frame)` for a still picture.
+impl<I: Iterator<Item = Frame>> Render for I {
+    fn next_frame(&mut self) -> Option<Frame> {
+        self.next()
+    }
+}
+
+/// What the viewer asked for between two frames.
+enum Control {
+    Next,
+    Quit,
+}
+
+/// Plays a [`Render`] in the terminal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Player {
+    pub fps: u32,
+    pub colour: bool,
+    /// Read the keyboard: space pauses, `n` or right steps while paused, `+`/`-` change
+    /// the frame rate and `q` quits. Needs a terminal on stdin.
+    pub interactive: bool,
+    /// Start paused, waiting for a step
+    pub paused: bool,
+    /// Largest picture drawn, in cells, leaving the rest for the caption
+    pub viewport: (usize, usize),
+}
+
+impl Default for Player {
+    fn default() -> Player {
+        Player {
+            fps: 20,
+            colour: true,
+            interactive: false,
+            paused: false,
+            viewport: (80, 23),
+        }
+    }
+}
+
+impl Player {
+    /// Player fitting the current terminal, keyboard controls included.
+    pub fn for_terminal(fps: u32, colour: bool, paused: bool) -> Player {
+        let viewport = terminal::size()
+            .map(|(width, height)| (width as usize, (height as usize).saturating_sub(2)))
+            .unwrap_or(Player::default().viewport);
+
+        Player {
+            fps,
+            colour,
+            interactive: true,
+            paused,
+            viewport,
+        }
+    }
+
+    /// Draw every frame of `render` on `out`, returning how many were shown.
+    pub fn play(&self, render: &mut dyn Render, out: &mut dyn Write) -> io::Result<usize> {
+        if self.interactive {
+            terminal::enable_raw_mode()?;
+        }
+        // Hide the cursor and clear the screen
+        write!(out, "\x1b[?25l\x1b[2J")?;
+
+        let result = self.play_frames(render, out);
+
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        if self.interactive {
+            terminal::disable_raw_mode()?;
+        }
+
+        result
+    }
+
+    fn play_frames(&self, render: &mut dyn Render, out: &mut dyn Write) -> io::Result<usize> {
+        let mut fps = self.fps.max(1);
+        let mut paused = self.paused;
+        let mut frames = 0;
+
+        while let Some(frame) = render.next_frame() {
+            write!(
+                out,
+                "\x1b[H{}\x1b[J",
+                frame.to_ansi(self.viewport, self.colour)
+            )?;
+            out.flush()?;
+            frames += 1;
+
+            let delay = Duration::from_secs(1) / fps;
+
+            if !self.interactive {
+                thread::sleep(delay);
+                continue;
+            }
+
+            if let Control::Quit = wait_for_control(delay, &mut paused, &mut fps)? {
+                break;
+            }
+        }
+
+        Ok(frames)
+    }
+}
+
+/// Wait `delay`, or for as long as the viewer keeps the playback paused.
+fn wait_for_control(delay: Duration, paused: &mut bool, fps: &mut u32) -> io::Result<Control> {
+    loop {
+        if !*paused && !event::poll(delay)? {
+            return Ok(Control::Next);
+        }
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(Control::Quit),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(Control::Quit)
+            }
+            KeyCode::Char(' ') => *paused = !*paused,
+            KeyCode::Char('n') | KeyCode::Right if *paused => return Ok(Control::Next),
+            KeyCode::Char('+') => *fps = (*fps * 2).min(1000),
+            KeyCode::Char('-') => *fps = (*fps / 2).max(1),
+            _ => {}
+        }
+
+        if !*paused {
+            return Ok(Control::Next);
+        }
+    }
+}
+
+// ================ Unit Tests ================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl Render for Countdown {
+        fn next_frame(&mut self) -> Option<Frame> {
+            self.0 = self.0.checked_sub(1)?;
+
+            let mut cells = Grid::new(3, 1, Cell::plain('.'));
+            cells[(self.0, 0)] = Cell::new('#', Colour::Red);
+
+            Some(Frame {
+                cells,
+                caption: format!("{} left", self.0),
+                focus: None,
+            })
+        }
+    }
+
+    #[test]
+    fn nominal_frame_to_ansi() {
+        let frame = Countdown(2).next_frame().unwrap();
+
+        assert_eq!(frame.to_ansi((80, 24), false), ".#.\r\n1 left\r\n");
+        assert_eq!(
+            frame.to_ansi((80, 24), true),
+            ".\x1b[0m\x1b[31m#\x1b[0m.\r\n1 left\r\n"
+        );
+    }
+
+    #[test]
+    fn nominal_colour_paint() {
+        assert_eq!(Colour::Yellow.paint("42"), "\x1b[33m42\x1b[0m");
+    }
+
+    #[test]
+    fn nominal_frame_crop() {
+        let rows = (0..5)
+            .map(|y| {
+                (0..5)
+                    .map(|x| Cell::plain((b'a' + x + y * 5) as char))
+                    .collect()
+            })
+            .collect();
+        let frame = Frame {
+            cells: Grid::from_rows(rows).unwrap(),
+            caption: String::new(),
+            focus: Some((4, 0)),
+        };
+
+        assert_eq!(frame.to_ansi((2, 2), false), "de\r\nij\r\n\r\n");
+    }
+
+    #[test]
+    fn nominal_player() {
+        let player = Player {
+            fps: 1000,
+            colour: false,
+            ..Player::default()
+        };
+        let mut out = Vec::new();
+
+        assert_eq!(player.play(&mut Countdown(3), &mut out).unwrap(), 3);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\x1b[?25l\x1b[2J\x1b[H..#\r\n2 left\r\n\x1b[J"));
+        assert!(out.ends_with("\x1b[H#..\r\n0 left\r\n\x1b[J\x1b[?25h"));
+    }
+}
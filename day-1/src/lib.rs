@@ -0,0 +1,116 @@
+//! --- Day 1: Calorie Counting ---
+
+use common::parse::{blocks, lines, number};
+use common::{Answer, ParseError, Rng, Solution};
+
+pub struct Day1;
+
+fn count_calories(file_content: &str) -> Result<Vec<i32>, ParseError> {
+    let split_groups = blocks(file_content);
+    let mut vec = Vec::new();
+
+    // Iterate over calorie groups
+    for group in split_groups {
+        let split_lines = lines(group);
+        let mut calories = 0;
+
+        // Iterate over each calorie in a group
+        for line in split_lines {
+            calories += number::<i32>(file_content, line)?;
+        }
+
+        vec.push(calories);
+    }
+
+    Ok(vec)
+}
+
+fn generate_calories(size: usize, rng: &mut Rng) -> String {
+    // Part two needs at least three Elves
+    let groups: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                .collect()
+        })
+        .collect();
+
+    groups.join("\n")
+}
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    const DAY: u32 = 1;
+    const NAME: &'static str = "--- Day 1: Calorie Counting ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Find the Elf carrying the most Calories. How many total Calories is that Elf carrying?",
+        "Find the top three Elves carrying the most Calories. \
+        How many Calories are those Elves carrying in total?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        count_calories(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut calories_group_max = 0;
+
+        // Find Max
+        for &calories in input {
+            if calories > calories_group_max {
+                calories_group_max = calories;
+            }
+        }
+
+        calories_group_max.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut vec = input.clone();
+
+        // Sort elfs with highest calories
+        vec.sort();
+
+        // Find how much calories do the 3 top elves have together, or all of them if fewer
+        let top_3_elves: i32 = vec.iter().rev().take(3).sum();
+
+        top_3_elves.into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_calories(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day1, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Calories of every Elf, most first
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let mut elves: Vec<i32> = file_content
+            .replace("\r\n", "\n")
+            .split("\n\n")
+            .map(|group| group.lines().map(|line| line.parse::<i32>().unwrap()).sum())
+            .collect();
+        elves.sort_by(|a, b| b.cmp(a));
+
+        [elves[0].into(), (elves[0] + elves[1] + elves[2]).into()]
+    }
+
+    #[test]
+    fn nominal_fewer_than_three_elves() {
+        let input = Day1::parse("5\n\n7\n").unwrap();
+        assert_eq!(Day1::part_two(&input), Answer::from(12));
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day1>(100, 50, naive_answers);
+    }
+}
@@ -0,0 +1,522 @@
+//! --- Day 10: Cathode-Ray Tube ---
+
+use common::parse::{lines, number, words};
+use common::viz::{Cell, Colour, Frame, Render};
+use common::{Answer, Config, ConfigError, Grid, ParseError, Rng, Solution};
+use serde::Deserialize;
+
+pub struct Day10;
+
+/// Tunable constants, set in `config.toml` or with `--set`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Constants {
+    /// Cycles whose signal strengths are summed by part one, in increasing order
+    probe_cycles: Vec<u32>,
+    crt_width: usize,
+    crt_height: usize,
+}
+
+impl Default for Constants {
+    fn default() -> Self {
+        Constants {
+            probe_cycles: vec![20, 60, 100, 140, 180, 220],
+            crt_width: 40,
+            crt_height: 6,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Program {
+    instructions: Vec<InstructionSet>,
+    constants: Constants,
+}
+
+#[derive(Debug, Copy)]
+enum Instruction {
+    Noop,
+    Addx,
+}
+
+impl Instruction {
+    fn from_str(value: &str) -> Option<Instruction> {
+        match value {
+            "noop" => Some(Instruction::Noop),
+            "addx" => Some(Instruction::Addx),
+            _ => None,
+        }
+    }
+
+    fn get_cycles(value: Instruction) -> u32 {
+        match value {
+            Instruction::Noop => 1,
+            Instruction::Addx => 2,
+        }
+    }
+}
+
+impl Clone for Instruction {
+    fn clone(&self) -> Instruction {
+        *self
+    }
+}
+
+#[derive(Debug, Copy)]
+pub struct InstructionSet {
+    instruction: Instruction,
+    value: i32,
+}
+
+impl Clone for InstructionSet {
+    fn clone(&self) -> InstructionSet {
+        *self
+    }
+}
+
+#[derive(Debug)]
+struct Probe {
+    cycles: Vec<u32>,
+    cycle_index: usize,
+    signal_strength: Vec<(u32, i32)>,
+}
+
+#[derive(Debug)]
+struct Cpu {
+    program: Vec<InstructionSet>,
+    cycles: u32,
+    x: i32,
+    pipe: InstructionSet,
+    instruction_cycles: u32,
+    next_instruction_index: usize,
+    busy: bool,
+    probe: Probe,
+    crt: Crt,
+}
+
+impl Default for Cpu {
+    fn default() -> Cpu {
+        Cpu {
+            program: Vec::new(),
+            cycles: 0,
+            x: 1,
+            pipe: InstructionSet {
+                instruction: Instruction::Noop,
+                value: 0,
+            },
+            instruction_cycles: 0,
+            next_instruction_index: 0,
+            busy: false,
+            probe: Probe {
+                cycles: Vec::new(),
+                cycle_index: 0,
+                signal_strength: Vec::new(),
+            },
+            crt: Crt {
+                grid: Grid::default(),
+            },
+        }
+    }
+}
+
+impl Cpu {
+    fn load_program(&mut self, program: Vec<InstructionSet>) {
+        self.program = program;
+    }
+
+    fn load_probe(&mut self, probe_cycles: Vec<u32>) {
+        self.probe.cycles = probe_cycles;
+    }
+
+    fn create_crt_screen(&mut self, x: usize, y: usize) {
+        self.crt = Crt {
+            grid: Grid::new(x, y, ' '),
+        };
+    }
+
+    fn run(&mut self, probe_enabled: bool, crt_screen_enabled: bool) {
+        while self.is_running() {
+            self.step(probe_enabled, crt_screen_enabled);
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        (self.next_instruction_index < self.program.len())
+            || ((self.next_instruction_index == self.program.len()) && (self.busy))
+    }
+
+    // Run a single cycle
+    fn step(&mut self, probe_enabled: bool, crt_screen_enabled: bool) {
+        self.fetch_instruction();
+        self.process_instruction();
+        self.run_cycle();
+        if probe_enabled {
+            self.probe_signal_strength();
+        }
+        if crt_screen_enabled {
+            self.draw_crt_screen();
+        }
+        self.execute_instruction();
+    }
+
+    // Nothing is left to fetch while the last instruction completes
+    fn fetch_instruction(&mut self) {
+        if !self.busy && self.next_instruction_index < self.program.len() {
+            let instruction_set = self.program[self.next_instruction_index];
+
+            self.pipe = instruction_set;
+            self.instruction_cycles = Instruction::get_cycles(instruction_set.instruction);
+            self.next_instruction_index += 1;
+        }
+    }
+
+    fn process_instruction(&mut self) {
+        if self.instruction_cycles != 0 {
+            self.instruction_cycles -= 1;
+        }
+    }
+
+    fn run_cycle(&mut self) {
+        self.cycles += 1;
+    }
+
+    // Store signal strengths
+    fn probe_signal_strength(&mut self) {
+        if (self.probe.cycle_index < self.probe.cycles.len())
+            && (self.cycles == self.probe.cycles[self.probe.cycle_index])
+        {
+            self.probe.cycle_index += 1;
+            self.probe.signal_strength.push((self.cycles, self.x));
+        }
+    }
+
+    fn draw_crt_screen(&mut self) {
+        let i = (self.cycles as usize - 1) / self.crt.grid.width();
+        let j = (self.cycles as usize - 1) % self.crt.grid.width();
+        let cursor = j as i32;
+
+        // A smaller screen than the program misses its last pixels
+        if i >= self.crt.grid.height() {
+            return;
+        }
+
+        if (cursor == (self.x - 1)) || (cursor == self.x) || (cursor == (self.x + 1)) {
+            self.crt.grid[(j, i)] = '#';
+        } else {
+            self.crt.grid[(j, i)] = '.';
+        }
+    }
+
+    fn execute_instruction(&mut self) {
+        self.busy = true;
+
+        if self.instruction_cycles == 0 {
+            match self.pipe.instruction {
+                Instruction::Noop => {}
+                Instruction::Addx => {
+                    self.x += self.pipe.value;
+                }
+            }
+            self.busy = false;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Crt {
+    grid: Grid<char>,
+}
+
+fn get_sum_of_signal_strength(signal_strength: Vec<(u32, i32)>) -> i64 {
+    let mut sum = 0;
+
+    for signal in signal_strength {
+        sum += signal.0 as i64 * signal.1 as i64;
+    }
+
+    sum
+}
+
+fn parse_input_file(file_content: &str) -> Result<Vec<InstructionSet>, ParseError> {
+    let lines = lines(file_content);
+    let mut program: Vec<InstructionSet> = Vec::new();
+
+    for line in lines {
+        let name = line.split_whitespace().next().unwrap_or(line);
+        let instruction = Instruction::from_str(name)
+            .ok_or_else(|| ParseError::at(file_content, name, "an instruction (noop or addx)"))?;
+        let value = match instruction {
+            Instruction::Noop => {
+                words::<1>(file_content, line)?;
+                0
+            }
+            Instruction::Addx => {
+                let [_, value] = words::<2>(file_content, line)?;
+                number::<i32>(file_content, value)?
+            }
+        };
+
+        program.push(InstructionSet { instruction, value });
+    }
+
+    Ok(program)
+}
+
+// The CRT draws one pixel per cycle, so the program runs for exactly its 240 cycles
+fn generate_program(rng: &mut Rng) -> String {
+    let mut program = String::new();
+    let mut cycles = 0;
+    let mut x: i32 = 1;
+
+    while cycles < 240 {
+        if cycles == 239 || rng.chance(0.3) {
+            program += "noop\n";
+            cycles += 1;
+        } else {
+            // The sprite wanders around the screen and a little past its edges, so X and
+            // the signal strengths also go negative
+            let value = (x + rng.range(0..=20) as i32 - 10).clamp(-10, 49) - x;
+            program += &format!("addx {}\n", value);
+            x += value;
+            cycles += 2;
+        }
+    }
+
+    program
+}
+
+#[allow(dead_code)]
+fn print_program(program: &Vec<InstructionSet>) {
+    for instruction_set in program {
+        println!("{:?}", instruction_set);
+    }
+}
+
+fn crt_screen_to_lines(crt_screen: Crt) -> Vec<String> {
+    crt_screen.grid.rows().map(String::from_iter).collect()
+}
+
+/// The CRT beam drawing the screen, one cycle per frame.
+struct CrtRender {
+    cpu: Cpu,
+}
+
+impl Render for CrtRender {
+    fn next_frame(&mut self) -> Option<Frame> {
+        if !self.cpu.is_running() {
+            return None;
+        }
+
+        // The beam draws the pixel of the cycle being run
+        let width = self.cpu.crt.grid.width();
+        let beam = (
+            self.cpu.cycles as usize % width,
+            self.cpu.cycles as usize / width,
+        );
+        if beam.1 >= self.cpu.crt.grid.height() {
+            return None;
+        }
+        let sprite_x = self.cpu.x;
+        self.cpu.step(false, true);
+
+        let mut rows: Vec<Vec<Cell>> = self
+            .cpu
+            .crt
+            .grid
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|&pixel| match pixel {
+                        '#' => Cell::new('#', Colour::Green),
+                        '.' => Cell::new('.', Colour::Grey),
+                        _ => Cell::plain(pixel),
+                    })
+                    .collect()
+            })
+            .collect();
+        rows[beam.1][beam.0].colour = Some(Colour::Highlight);
+
+        // Sprite under the screen, where the beam reads it
+        rows.push(
+            (0..width as i32)
+                .map(|x| {
+                    if (x - sprite_x).abs() <= 1 {
+                        Cell::new('=', Colour::Yellow)
+                    } else {
+                        Cell::plain(' ')
+                    }
+                })
+                .collect(),
+        );
+
+        Some(Frame {
+            cells: Grid::from_rows(rows).expect("CRT rows should have the same width"),
+            caption: format!("Cycle {:3}  X = {}", self.cpu.cycles, sprite_x),
+            focus: Some(beam),
+        })
+    }
+}
+
+impl Solution for Day10 {
+    type Input = Program;
+
+    const DAY: u32 = 10;
+    const NAME: &'static str = "--- Day 10: Cathode-Ray Tube ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and 220th cycles. \
+        What is the sum of these six signal strengths?",
+        "Render the image given by your program. What eight capital letters appear on your CRT?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        Ok(Program {
+            instructions: parse_input_file(file_content)?,
+            constants: Constants::default(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let probe_cycles = input.constants.probe_cycles.clone();
+        let mut cpu = Cpu::default();
+
+        cpu.load_program(input.instructions.clone());
+        cpu.load_probe(probe_cycles);
+        cpu.run(true, false);
+
+        get_sum_of_signal_strength(cpu.probe.signal_strength).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut cpu = Cpu::default();
+
+        cpu.load_program(input.instructions.clone());
+        cpu.create_crt_screen(input.constants.crt_width, input.constants.crt_height);
+        cpu.run(false, true);
+
+        Answer::Grid(crt_screen_to_lines(cpu.crt))
+    }
+
+    fn configure(input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        let constants: Constants = config.to_typed()?;
+        if !constants
+            .probe_cycles
+            .windows(2)
+            .all(|pair| pair[0] < pair[1])
+        {
+            return Err(ConfigError::new(
+                "Probe cycles should be in increasing order",
+            ));
+        }
+        if constants.crt_width == 0 || constants.crt_height == 0 {
+            return Err(ConfigError::new("The CRT should have at least one pixel"));
+        }
+
+        input.constants = constants;
+        Ok(())
+    }
+
+    /// The program always fills the screen, `size` is ignored.
+    fn generate(_size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_program(rng))
+    }
+
+    fn visualize(input: &Self::Input, part: usize) -> Option<Box<dyn Render + '_>> {
+        if part != 2 {
+            return None;
+        }
+
+        let mut cpu = Cpu::default();
+
+        cpu.load_program(input.instructions.clone());
+        cpu.create_crt_screen(input.constants.crt_width, input.constants.crt_height);
+
+        Some(Box::new(CrtRender { cpu }))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day10, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Value of X during each cycle, written out in full
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let mut x = 1;
+        let mut values = Vec::new();
+
+        for line in file_content.lines() {
+            values.push(x);
+            if let Some(value) = line.strip_prefix("addx ") {
+                values.push(x);
+                x += value.parse::<i32>().unwrap();
+            }
+        }
+
+        let strength: i32 = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|&cycle| cycle as i32 * values[cycle - 1])
+            .sum();
+        let screen = values
+            .chunks(40)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(column, x)| {
+                        if (column as i32 - x).abs() <= 1 {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        [strength.into(), Answer::Grid(screen)]
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day10>(100, 1, naive_answers);
+    }
+
+    #[test]
+    fn nominal_ending_on_addx() {
+        let input = Day10::parse("addx 3\n").unwrap();
+        let screen = Day10::part_two(&input);
+
+        assert_eq!(Day10::part_one(&input), Answer::from(0));
+        assert_eq!(screen.lines()[0], format!("##{}", " ".repeat(38)));
+    }
+
+    #[test]
+    fn nominal_negative_signal_strength() {
+        let input = Day10::parse(&format!("addx -20\n{}", "noop\n".repeat(238))).unwrap();
+
+        // X is -19 at every probed cycle, 20 + 60 + ... + 220 = 720
+        assert_eq!(Day10::part_one(&input), Answer::from(-19 * 720));
+    }
+
+    #[test]
+    fn nominal_configure() {
+        let mut input = Day10::parse("noop\naddx 3\naddx -5\nnoop\n").unwrap();
+        let mut config = Config::default();
+        config.set("probe_cycles=[2, 4]").unwrap();
+        config.set("crt_width=3").unwrap();
+        config.set("crt_height=1").unwrap();
+        Day10::configure(&mut input, &config).unwrap();
+
+        // X is 1 during cycle 2 and 4 during cycle 4, and cycles after the 3rd are off-screen
+        assert_eq!(Day10::part_one(&input), Answer::from(18));
+        assert_eq!(
+            Day10::part_two(&input),
+            Answer::Grid(vec!["###".to_string()])
+        );
+
+        config.set("probe_cycles=[4, 2]").unwrap();
+        assert!(Day10::configure(&mut input, &config).is_err());
+    }
+}
@@ -0,0 +1,597 @@
+//! --- Day 11: Monkey in the Middle ---
+
+use common::parse::{blocks, lines, number, words};
+use common::{Answer, Config, ConfigError, ParseError, Rng, Solution};
+use serde::Deserialize;
+
+pub struct Day11;
+
+/// Tunable constants, set in `config.toml` or with `--set`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Constants {
+    rounds_part_one: usize,
+    rounds_part_two: usize,
+    /// Divides the worry levels after each inspection of part one
+    relief: u64,
+}
+
+impl Default for Constants {
+    fn default() -> Self {
+        Constants {
+            rounds_part_one: 20,
+            rounds_part_two: 10000,
+            relief: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    divisible: u128,
+    if_true: u128,
+    if_false: u128,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Operator {
+    Sum,
+    Mul,
+}
+
+impl Operator {
+    fn from_char(value: char) -> Option<Operator> {
+        match value {
+            '+' => Some(Operator::Sum),
+            '*' => Some(Operator::Mul),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    operator: Operator,
+    value: u128,
+    old: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    id: u128,
+    starting_items: Vec<u128>,
+    operation: Operation,
+    test: Test,
+    inspect_counter: u128,
+}
+
+impl Default for Monkey {
+    fn default() -> Monkey {
+        Monkey {
+            id: 0,
+            starting_items: Vec::new(),
+            operation: Operation {
+                operator: Operator::Sum,
+                value: 0,
+                old: false,
+            },
+            test: Test {
+                divisible: 0,
+                if_true: 0,
+                if_false: 0,
+            },
+            inspect_counter: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MonkeyGang {
+    monkey: Vec<Monkey>,
+    relieved: bool,
+    constants: Constants,
+}
+
+/// Number of the monkey thrown to, one of the `count` monkeys of the notes.
+fn parse_target(file_content: &str, token: &str, count: usize) -> Result<u128, ParseError> {
+    let target = number::<u128>(file_content, token)?;
+
+    if target < count as u128 {
+        Ok(target)
+    } else {
+        Err(ParseError::at(
+            file_content,
+            token,
+            format!("a monkey of the notes (0 to {})", count - 1),
+        ))
+    }
+}
+
+/// Monkey of `monkey_note`, which may only throw to the `count` monkeys of the notes.
+fn parse_monkey_note(
+    file_content: &str,
+    monkey_note: &str,
+    count: usize,
+) -> Result<Monkey, ParseError> {
+    let mut monkey = Monkey::default();
+
+    for line in lines(monkey_note) {
+        if let Some(id) = line.strip_prefix("Monkey ") {
+            let id = id.strip_suffix(':').unwrap_or(id);
+            monkey.id = number::<u128>(file_content, id)?;
+        } else if let Some(items) = line.strip_prefix("  Starting items: ") {
+            monkey.starting_items = items
+                .split(", ")
+                .map(|x| number::<u128>(file_content, x))
+                .collect::<Result<_, _>>()?;
+        } else if let Some(operation) = line.strip_prefix("  Operation: new = old ") {
+            let [operator, operand] = words::<2>(file_content, operation)?;
+            let operator = match operator {
+                "+" => Operator::from_char('+'),
+                "*" => Operator::from_char('*'),
+                _ => None,
+            }
+            .ok_or_else(|| ParseError::at(file_content, operator, "an operator (+ or *)"))?;
+
+            if operand == "old" {
+                monkey.operation = Operation {
+                    old: true,
+                    operator,
+                    value: 0,
+                };
+            } else {
+                monkey.operation = Operation {
+                    old: false,
+                    operator,
+                    value: number::<u128>(file_content, operand)?,
+                };
+            }
+        } else if let Some(divisible) = line.strip_prefix("  Test: divisible by ") {
+            monkey.test.divisible = number::<u128>(file_content, divisible)?;
+
+            if monkey.test.divisible == 0 {
+                return Err(ParseError::at(
+                    file_content,
+                    divisible,
+                    "a non-zero divisor",
+                ));
+            }
+        } else if let Some(if_true) = line.strip_prefix("    If true: throw to monkey ") {
+            monkey.test.if_true = parse_target(file_content, if_true, count)?;
+        } else if let Some(if_false) = line.strip_prefix("    If false: throw to monkey ") {
+            monkey.test.if_false = parse_target(file_content, if_false, count)?;
+        } else {
+            return Err(ParseError::at(file_content, line, "a line of monkey notes"));
+        }
+    }
+
+    // Without its test, the monkey would divide by 0
+    if monkey.test.divisible == 0 {
+        return Err(ParseError::at(
+            file_content,
+            monkey_note,
+            "a note with a `Test: divisible by` line",
+        ));
+    }
+
+    Ok(monkey)
+}
+
+fn parse_monkey_notes(file_content: &str) -> Result<MonkeyGang, ParseError> {
+    let monkey_notes = blocks(file_content);
+    let mut monkey_gang: MonkeyGang = MonkeyGang::default();
+
+    // Monkey business is the product of the two most active monkeys
+    if monkey_notes.len() < 2 {
+        return Err(ParseError::at(
+            file_content,
+            monkey_notes.last().copied().unwrap_or(file_content),
+            "notes on at least 2 monkeys",
+        ));
+    }
+
+    for monkey_note in &monkey_notes {
+        monkey_gang.monkey.push(parse_monkey_note(
+            file_content,
+            monkey_note,
+            monkey_notes.len(),
+        )?);
+    }
+
+    Ok(monkey_gang)
+}
+
+fn monkey_note(monkey: &Monkey) -> String {
+    let items: Vec<String> = monkey
+        .starting_items
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+    let operator = match monkey.operation.operator {
+        Operator::Sum => '+',
+        Operator::Mul => '*',
+    };
+    let operand = if monkey.operation.old {
+        "old".to_string()
+    } else {
+        monkey.operation.value.to_string()
+    };
+
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  \
+        Test: divisible by {}\n    If true: throw to monkey {}\n    \
+        If false: throw to monkey {}\n",
+        monkey.id,
+        items.join(", "),
+        operator,
+        operand,
+        monkey.test.divisible,
+        monkey.test.if_true,
+        monkey.test.if_false
+    )
+}
+
+// Worry levels are only divided by 3 in part one, check they never overflow in its rounds
+fn worry_levels_fit(monkeys: &[Monkey], rounds: usize) -> bool {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[i].starting_items) {
+                let operation = &monkeys[i].operation;
+                let operand = if operation.old { item } else { operation.value };
+                let worry_level = match operation.operator {
+                    Operator::Sum => item.checked_add(operand),
+                    Operator::Mul => item.checked_mul(operand),
+                };
+                let Some(worry_level) = worry_level else {
+                    return false;
+                };
+
+                let test = &monkeys[i].test;
+                let next_monkey = if (worry_level / 3).is_multiple_of(test.divisible) {
+                    test.if_true
+                } else {
+                    test.if_false
+                };
+                monkeys[next_monkey as usize]
+                    .starting_items
+                    .push(worry_level / 3);
+            }
+        }
+    }
+
+    true
+}
+
+fn generate_monkey(rng: &mut Rng, id: usize, count: usize, divisible: u128) -> Monkey {
+    // Monkeys never throw to themselves, and only have one other monkey to throw to when
+    // there are two of them
+    let mut others: Vec<u128> = (0..count as u128)
+        .filter(|&other| other != id as u128)
+        .collect();
+    rng.shuffle(&mut others);
+    let (if_true, if_false) = (others[0], *others.last().unwrap());
+
+    let operation = if rng.chance(1.0 / count as f64) {
+        Operation {
+            operator: Operator::Mul,
+            value: 0,
+            old: true,
+        }
+    } else if rng.chance(0.4) {
+        Operation {
+            operator: Operator::Mul,
+            value: rng.range(2..=19) as u128,
+            old: false,
+        }
+    } else {
+        Operation {
+            operator: Operator::Sum,
+            value: rng.range(1..=8) as u128,
+            old: false,
+        }
+    };
+
+    Monkey {
+        id: id as u128,
+        starting_items: (0..rng.range(1..=6))
+            .map(|_| rng.range(50..=99) as u128)
+            .collect(),
+        operation,
+        test: Test {
+            divisible,
+            if_true,
+            if_false,
+        },
+        inspect_counter: 0,
+    }
+}
+
+fn generate_monkey_notes(size: usize, rng: &mut Rng) -> String {
+    // Distinct primes keep their product, used to bound worry levels in part two, small
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let count = size.clamp(2, divisors.len());
+
+    loop {
+        rng.shuffle(&mut divisors);
+        let monkeys: Vec<Monkey> = (0..count)
+            .map(|id| generate_monkey(rng, id, count, divisors[id]))
+            .collect();
+
+        if worry_levels_fit(&monkeys, 20) {
+            let notes: Vec<String> = monkeys.iter().map(monkey_note).collect();
+
+            return notes.join("\n");
+        }
+    }
+}
+
+fn monkey_business_process(mut monkey_gang: MonkeyGang) -> MonkeyGang {
+    let mut lcm: u128 = 1;
+
+    // Find LCM (Least common multiple) - simplified due to prime numbers
+    for i in 0..monkey_gang.monkey.len() {
+        lcm *= monkey_gang.monkey[i].test.divisible
+    }
+
+    // Iterate each Monkey
+    for i in 0..monkey_gang.monkey.len() {
+        // Iterate each item Monkey is holding
+        for j in 0..monkey_gang.monkey[i].starting_items.len() {
+            let mut new_worry_level: u128 = 0;
+
+            // Monkey inspects an element
+            let item = monkey_gang.monkey[i].starting_items[j];
+
+            // Increment the inspect counter
+            monkey_gang.monkey[i].inspect_counter += 1;
+
+            // Decide whether to use old or specific value
+            let second_operand = if !monkey_gang.monkey[i].operation.old {
+                monkey_gang.monkey[i].operation.value
+            } else {
+                item
+            };
+
+            // Decide on the operation
+            if monkey_gang.monkey[i].operation.operator == Operator::Mul {
+                new_worry_level = item * second_operand;
+            } else if monkey_gang.monkey[i].operation.operator == Operator::Sum {
+                new_worry_level = item + second_operand;
+            }
+
+            // Boring procedure. Without relief, only the remainders of the tests matter, so
+            // worry levels are kept below the lcm instead of growing out of range
+            if monkey_gang.relieved && monkey_gang.constants.relief > 1 {
+                new_worry_level /= monkey_gang.constants.relief as u128;
+            } else {
+                new_worry_level %= lcm;
+            }
+
+            // Test procedure
+            let next_monkey =
+                if new_worry_level.is_multiple_of(monkey_gang.monkey[i].test.divisible) {
+                    monkey_gang.monkey[i].test.if_true as usize
+                } else {
+                    monkey_gang.monkey[i].test.if_false as usize
+                };
+
+            // Throw to next Monkey
+            monkey_gang.monkey[next_monkey]
+                .starting_items
+                .push(new_worry_level);
+        }
+
+        // Clear whatever item the current Monkey had
+        monkey_gang.monkey[i].starting_items.clear();
+    }
+
+    monkey_gang
+}
+
+fn monkey_business(monkey_gang: &MonkeyGang, iter: usize, relieved: bool) -> u128 {
+    let mut monkey_gang = monkey_gang.clone();
+    monkey_gang.relieved = relieved;
+
+    // Monkeys play N rounds
+    for _ in 0..iter {
+        monkey_gang = monkey_business_process(monkey_gang);
+    }
+
+    // Find 2 most active Monkeys
+    let mut inspect_vec: Vec<u128> = Vec::new();
+
+    for i in 0..monkey_gang.monkey.len() {
+        inspect_vec.push(monkey_gang.monkey[i].inspect_counter);
+    }
+
+    inspect_vec.sort();
+    let top_1 = inspect_vec.pop().unwrap();
+    let top_2 = inspect_vec.pop().unwrap();
+
+    top_1 * top_2
+}
+
+impl Solution for Day11 {
+    type Input = MonkeyGang;
+
+    const DAY: u32 = 11;
+    const NAME: &'static str = "--- Day 11: Monkey in the Middle ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Figure out which monkeys to chase by counting how many items they inspect over 20 rounds. \
+        What is the level of monkey business after 20 rounds of stuff-slinging simian shenanigans?",
+        "Starting again from the initial state in your puzzle input, \
+        what is the level of monkey business after 10000 rounds?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        parse_monkey_notes(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        monkey_business(input, input.constants.rounds_part_one, true).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        monkey_business(input, input.constants.rounds_part_two, false).into()
+    }
+
+    fn configure(input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        let constants: Constants = config.to_typed()?;
+        if constants.relief == 0 {
+            return Err(ConfigError::new(
+                "Relief should not divide worry levels by 0",
+            ));
+        }
+
+        input.constants = constants;
+        Ok(())
+    }
+
+    /// At most 9 monkeys, one per prime divisor.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_monkey_notes(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day11, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    struct NaiveMonkey {
+        items: VecDeque<u128>,
+        operation: Vec<String>,
+        divisible: u128,
+        targets: [usize; 2],
+        inspected: u128,
+    }
+
+    // Last word of `line`, parsed
+    fn last_number<T: std::str::FromStr>(line: &str) -> T {
+        line.rsplit(' ').next().unwrap().parse().ok().unwrap()
+    }
+
+    // Notes read line by line, items thrown one at a time from a queue
+    fn naive_monkey_business(file_content: &str, rounds: usize, relief: bool) -> Answer {
+        let mut monkeys: Vec<NaiveMonkey> = file_content
+            .split("\n\n")
+            .map(|note| {
+                let lines: Vec<&str> = note.lines().collect();
+
+                NaiveMonkey {
+                    items: lines[1]
+                        .split(": ")
+                        .nth(1)
+                        .unwrap()
+                        .split(", ")
+                        .map(|item| item.parse().unwrap())
+                        .collect(),
+                    operation: lines[2]
+                        .split(' ')
+                        .rev()
+                        .take(2)
+                        .map(String::from)
+                        .collect(),
+                    divisible: last_number(lines[3]),
+                    targets: [last_number(lines[4]), last_number(lines[5])],
+                    inspected: 0,
+                }
+            })
+            .collect();
+        let modulus: u128 = monkeys.iter().map(|monkey| monkey.divisible).product();
+
+        for _ in 0..rounds {
+            for i in 0..monkeys.len() {
+                while let Some(item) = monkeys[i].items.pop_front() {
+                    let monkey = &mut monkeys[i];
+                    let operand = monkey.operation[0].parse().unwrap_or(item);
+                    let mut worry_level = match monkey.operation[1].as_str() {
+                        "+" => item + operand,
+                        _ => item * operand,
+                    };
+
+                    if relief {
+                        worry_level /= 3;
+                    } else {
+                        worry_level %= modulus;
+                    }
+                    monkey.inspected += 1;
+
+                    let target = monkey.targets[(worry_level % monkey.divisible != 0) as usize];
+                    monkeys[target].items.push_back(worry_level);
+                }
+            }
+        }
+
+        let mut inspected: Vec<u128> = monkeys.iter().map(|monkey| monkey.inspected).collect();
+        inspected.sort_by(|a, b| b.cmp(a));
+
+        (inspected[0] * inspected[1]).into()
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day11>(30, 9, |file_content| {
+            [
+                naive_monkey_business(file_content, 20, true),
+                naive_monkey_business(file_content, 10000, false),
+            ]
+        });
+    }
+
+    #[test]
+    fn invalid_monkey_notes() {
+        let example = include_str!("../input/example-1.txt");
+        let (first, _) = example.split_once("\n\n").unwrap();
+
+        let error = Day11::parse(first).unwrap_err();
+        assert_eq!(error.expected, "notes on at least 2 monkeys");
+
+        let error = Day11::parse(&example.replacen("throw to monkey 3", "throw to monkey 4", 1))
+            .unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (6, "4"));
+        assert_eq!(error.expected, "a monkey of the notes (0 to 3)");
+
+        let error =
+            Day11::parse(&example.replacen("divisible by 23", "divisible by 0", 1)).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (4, "a non-zero divisor")
+        );
+
+        let error =
+            Day11::parse(&example.replacen("  Test: divisible by 23\n", "", 1)).unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+
+    #[test]
+    fn nominal_configure() {
+        let mut input = Day11::parse(include_str!("../input/example-1.txt")).unwrap();
+        let mut config = Config::default();
+        config.set("rounds_part_one=1").unwrap();
+        config.set("rounds_part_two=1").unwrap();
+        Day11::configure(&mut input, &config).unwrap();
+
+        // Inspections after the first round of the puzzle statement, with and without relief
+        assert_eq!(Day11::part_one(&input), Answer::from(5 * 4));
+        assert_eq!(Day11::part_two(&input), Answer::from(6 * 4));
+
+        config.set("relief=1").unwrap();
+        Day11::configure(&mut input, &config).unwrap();
+        assert_eq!(Day11::part_one(&input), Day11::part_two(&input));
+
+        // Worry levels stay in range without relief, as in part two
+        config.set("rounds_part_one=10000").unwrap();
+        Day11::configure(&mut input, &config).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::from(2713310158u128));
+
+        config.set("relief=0").unwrap();
+        assert!(Day11::configure(&mut input, &config).is_err());
+    }
+}
@@ -0,0 +1,479 @@
+//! --- Day 12: Hill Climbing Algorithm ---
+
+extern crate queues;
+use common::geom::Dir4;
+use common::viz::{Cell, Colour, Frame, Render};
+use common::{Answer, Grid, ParseError, Rng, Solution};
+use queues::*;
+
+pub struct Day12;
+
+#[derive(Debug, Copy, Default)]
+struct Point {
+    x: usize,
+    y: usize,
+    height: i32,
+    visited: bool,
+    parent_x: usize,
+    parent_y: usize,
+}
+
+impl Clone for Point {
+    fn clone(&self) -> Point {
+        *self
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Heightmap {
+    map: Grid<Point>,
+    start: Point,
+    end: Point,
+}
+
+impl Heightmap {
+    fn get_adjacent_nodes(&mut self, point: Point) -> Vec<Point> {
+        self.map
+            .neighbours_4((point.x, point.y))
+            .map(|position| self.map[position])
+            .collect()
+    }
+
+    fn mark_visited(&mut self, point: Point) {
+        self.map[(point.x, point.y)].visited = true;
+    }
+
+    fn get_node(&mut self, point: Point) -> Point {
+        self.map[(point.x, point.y)]
+    }
+
+    fn is_node_equal(&mut self, point_a: Point, point_b: Point) -> bool {
+        (point_a.x == point_b.x) && (point_a.y == point_b.y)
+    }
+
+    fn register_parent(&mut self, point: Point, parent: Point) {
+        self.map[(point.x, point.y)].parent_x = parent.x;
+        self.map[(point.x, point.y)].parent_y = parent.y;
+    }
+
+    // Walk the parents back from `end`, which the search from `start` has to have visited
+    fn get_path(&mut self, start: Point, end: Point) -> Vec<Point> {
+        let mut path: Vec<Point> = Vec::new();
+        let mut node = self.get_node(end);
+
+        while !self.is_node_equal(start, node) {
+            path.push(node);
+            let parent = Point {
+                x: node.parent_x,
+                y: node.parent_y,
+                ..Default::default()
+            };
+            node = self.get_node(parent);
+        }
+
+        path
+    }
+
+    // Visit the unvisited adjacent nodes reachable from node, climbing up or down
+    fn visit_adjacent_nodes(&mut self, node: Point, climbing: bool) -> Vec<Point> {
+        let mut visited_nodes = Vec::new();
+
+        for adjacent_node in self.get_adjacent_nodes(node) {
+            let reachable = if climbing {
+                node.height >= (adjacent_node.height - 1)
+            } else {
+                node.height <= (adjacent_node.height + 1)
+            };
+
+            if (!adjacent_node.visited) && reachable {
+                self.mark_visited(adjacent_node);
+                self.register_parent(adjacent_node, node);
+                visited_nodes.push(adjacent_node);
+            }
+        }
+
+        visited_nodes
+    }
+
+    // `None` when the end cannot be reached from the start
+    fn find_shortest_path_start_to_end(&mut self) -> Option<Vec<Point>> {
+        let mut queue: Queue<Point> = queue![self.start];
+        self.mark_visited(self.start);
+
+        while queue.size() > 0 {
+            let node = queue.remove().unwrap();
+
+            for adjacent_node in self.visit_adjacent_nodes(node, true) {
+                let _ = queue.add(adjacent_node);
+            }
+        }
+
+        if !self.get_node(self.end).visited {
+            return None;
+        }
+
+        let mut path = self.get_path(self.start, self.end);
+        path.reverse();
+
+        Some(path)
+    }
+
+    // `None` when no square of elevation `a` can reach the end
+    fn find_shortest_path_lowest_to_end(&mut self) -> Option<Vec<Point>> {
+        let mut lowest_point = None;
+        let mut queue: Queue<Point> = queue![self.end];
+        self.mark_visited(self.end);
+
+        'outer: while queue.size() > 0 {
+            let node = queue.remove().unwrap();
+
+            for adjacent_node in self.visit_adjacent_nodes(node, false) {
+                let _ = queue.add(adjacent_node);
+
+                if adjacent_node.height == 0 {
+                    lowest_point = Some(adjacent_node);
+                    break 'outer;
+                }
+            }
+        }
+
+        lowest_point.map(|lowest_point| self.get_path(self.end, lowest_point))
+    }
+}
+
+fn char_to_value(char: char) -> Option<u8> {
+    let start_end = match char {
+        'S' => 'a',
+        'E' => 'z',
+        'a'..='z' => char,
+        _ => return None,
+    };
+
+    // 'a' => 0; 'z' => 25
+    Some((start_end as u8) - 97)
+}
+
+fn parse_input_file(file_content: &str) -> Result<Heightmap, ParseError> {
+    let mut heightmap: Heightmap = Heightmap::default();
+    let mut start_end = (false, false);
+
+    // Convert input content into a grid of chars, checking every elevation
+    let char_grid = Grid::parse(file_content, "an elevation (a-z, S or E)", |char| {
+        char_to_value(char).map(|_| char)
+    })?;
+
+    if char_grid.height() == 0 {
+        return Err(ParseError::at(file_content, file_content, "a heightmap"));
+    }
+
+    heightmap.map = Grid::new(char_grid.width(), char_grid.height(), Point::default());
+
+    // Convert grid of chars into Heightmap
+    for (x, y) in char_grid.positions() {
+        let char = char_grid[(x, y)];
+        let point = Point {
+            x,
+            y,
+            height: char_to_value(char).unwrap() as i32,
+            visited: false,
+            parent_x: 0,
+            parent_y: 0,
+        };
+        heightmap.map[(x, y)] = point;
+
+        if char == 'S' {
+            heightmap.start = point;
+            start_end.0 = true;
+        } else if char == 'E' {
+            heightmap.end = point;
+            start_end.1 = true;
+        }
+    }
+
+    if start_end != (true, true) {
+        return Err(ParseError::at(
+            file_content,
+            &file_content[file_content.len()..],
+            "a start (S) and an end (E) position",
+        ));
+    }
+
+    Ok(heightmap)
+}
+
+fn generate_heightmap(size: usize, rng: &mut Rng) -> String {
+    // As wide as real inputs, and enough for a path climbing from `a` to `z`
+    let height = size.max(1);
+    let width = (4 * height).max(34);
+
+    // A slope rising to the right, with some noise
+    let mut grid = Grid::new(width, height, 'a');
+    for (x, y) in grid.positions().collect::<Vec<_>>() {
+        let slope = (x * 25 / (width - 1)) as i32 + rng.range(0..=4) as i32 - 2;
+        grid[(x, y)] = (b'a' + slope.clamp(0, 25) as u8) as char;
+    }
+
+    // A path of right and vertical steps from the left edge to the end, climbing one
+    // letter at 25 of its steps so the end is always reachable
+    let start = (0, rng.below(height));
+    let end = (rng.range(width * 3 / 4..=width - 1), rng.below(height));
+    let mut steps = vec![Dir4::Right; end.0 - start.0];
+    let vertical = if end.1 > start.1 {
+        Dir4::Down
+    } else {
+        Dir4::Up
+    };
+    steps.extend(vec![vertical; end.1.abs_diff(start.1)]);
+    rng.shuffle(&mut steps);
+
+    let mut climbs = vec![true; 25];
+    climbs.resize(steps.len(), false);
+    rng.shuffle(&mut climbs);
+
+    let mut position = start;
+    let mut elevation = b'a';
+    for (&step, &climb) in steps.iter().zip(&climbs) {
+        position = grid.step(position, step.vector().into()).unwrap();
+        elevation += climb as u8;
+        grid[position] = elevation as char;
+    }
+    grid[start] = 'S';
+    grid[end] = 'E';
+
+    grid.to_string()
+}
+
+#[allow(dead_code)]
+fn print_heightmap(heightmap: &Heightmap) {
+    for line in heightmap.map.rows() {
+        for point in line {
+            print!("{:2}", point.height);
+        }
+        println!();
+    }
+}
+
+/// The breadth first search spreading over the heightmap, one layer per frame, ending
+/// on the shortest path it found.
+struct SearchRender {
+    heightmap: Heightmap,
+    frontier: Vec<Point>,
+    // Climbing from the start to the end, or down from the end to any lowest square
+    climbing: bool,
+    steps: usize,
+    path: Option<Vec<Point>>,
+}
+
+impl SearchRender {
+    fn new(heightmap: &Heightmap, climbing: bool) -> SearchRender {
+        let mut heightmap = heightmap.clone();
+        let origin = if climbing {
+            heightmap.start
+        } else {
+            heightmap.end
+        };
+        heightmap.mark_visited(origin);
+
+        SearchRender {
+            heightmap,
+            frontier: vec![origin],
+            climbing,
+            steps: 0,
+            path: None,
+        }
+    }
+
+    // Path found by the search once it reached its goal
+    fn found_path(&mut self) -> Option<Vec<Point>> {
+        if self.climbing {
+            let (start, end) = (self.heightmap.start, self.heightmap.end);
+            let end = self.heightmap.get_node(end);
+
+            end.visited.then(|| self.heightmap.get_path(start, end))
+        } else {
+            let end = self.heightmap.end;
+            let lowest_point = *self.frontier.iter().find(|point| point.height == 0)?;
+
+            Some(self.heightmap.get_path(end, lowest_point))
+        }
+    }
+}
+
+impl Render for SearchRender {
+    fn next_frame(&mut self) -> Option<Frame> {
+        if self.path.is_some() || self.frontier.is_empty() {
+            return None;
+        }
+
+        if self.steps > 0 {
+            let frontier = std::mem::take(&mut self.frontier);
+
+            for node in frontier {
+                let adjacent_nodes = self.heightmap.visit_adjacent_nodes(node, self.climbing);
+                self.frontier.extend(adjacent_nodes);
+            }
+        }
+        self.path = self.found_path();
+
+        let mut cells = self.heightmap.map.map(|point| {
+            let letter = (b'a' + point.height as u8) as char;
+
+            if point.visited {
+                Cell::new(letter, Colour::Blue)
+            } else {
+                Cell::plain(letter)
+            }
+        });
+
+        for point in &self.frontier {
+            cells[(point.x, point.y)].colour = Some(Colour::Highlight);
+        }
+        for point in self.path.iter().flatten() {
+            cells[(point.x, point.y)].colour = Some(Colour::Green);
+        }
+        let (start, end) = (self.heightmap.start, self.heightmap.end);
+        cells[(start.x, start.y)] = Cell::new('S', Colour::Red);
+        cells[(end.x, end.y)] = Cell::new('E', Colour::Red);
+
+        let caption = match &self.path {
+            Some(path) => format!("Step {}  Shortest path: {} steps", self.steps, path.len()),
+            None => format!(
+                "Step {}  Frontier: {} squares",
+                self.steps,
+                self.frontier.len()
+            ),
+        };
+        let focus = self.frontier.first().map(|point| (point.x, point.y));
+        self.steps += 1;
+
+        Some(Frame {
+            cells,
+            caption,
+            focus,
+        })
+    }
+}
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    const DAY: u32 = 12;
+    const NAME: &'static str = "--- Day 12: Hill Climbing Algorithm ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "What is the fewest steps required to move from your current position \
+        to the location that should get the best signal?",
+        "What is the fewest steps required to move starting from any square with elevation a \
+        to the location that should get the best signal?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        parse_input_file(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut heightmap = input.clone();
+
+        match heightmap.find_shortest_path_start_to_end() {
+            Some(path) => path.len().into(),
+            None => Answer::from("no path"),
+        }
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut heightmap = input.clone();
+
+        match heightmap.find_shortest_path_lowest_to_end() {
+            Some(path) => path.len().into(),
+            None => Answer::from("no path"),
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_heightmap(size, rng))
+    }
+
+    fn visualize(input: &Self::Input, part: usize) -> Option<Box<dyn Render + '_>> {
+        Some(Box::new(SearchRender::new(input, part == 1)))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day12, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Steps from each square to the end, relaxed over the whole map until nothing changes
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let rows: Vec<Vec<char>> = file_content
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        let elevation = |char: char| match char {
+            'S' => 0,
+            'E' => 25,
+            _ => char as i32 - 'a' as i32,
+        };
+        let (height, width) = (rows.len(), rows[0].len());
+        let mut steps = vec![vec![usize::MAX; width]; height];
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for y in 0..height {
+                for x in 0..width {
+                    let mut best = if rows[y][x] == 'E' { 0 } else { steps[y][x] };
+
+                    for (next_x, next_y) in [
+                        (x + 1, y),
+                        (x.wrapping_sub(1), y),
+                        (x, y + 1),
+                        (x, y.wrapping_sub(1)),
+                    ] {
+                        let Some(&next) = rows.get(next_y).and_then(|row| row.get(next_x)) else {
+                            continue;
+                        };
+                        if elevation(next) <= elevation(rows[y][x]) + 1
+                            && steps[next_y][next_x] != usize::MAX
+                        {
+                            best = best.min(steps[next_y][next_x] + 1);
+                        }
+                    }
+
+                    if best != steps[y][x] {
+                        steps[y][x] = best;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let squares = || (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+        let (start_x, start_y) = squares().find(|&(x, y)| rows[y][x] == 'S').unwrap();
+        let fewest = squares()
+            .filter(|&(x, y)| elevation(rows[y][x]) == 0)
+            .map(|(x, y)| steps[y][x])
+            .min()
+            .unwrap();
+
+        [steps[start_y][start_x].into(), fewest.into()]
+    }
+
+    #[test]
+    fn nominal_no_path() {
+        let input = Day12::parse("SbE\n").unwrap();
+        assert_eq!(Day12::part_one(&input), Answer::from("no path"));
+
+        let input = Day12::parse("aaa\nSbE\n").unwrap();
+        assert_eq!(Day12::part_one(&input), Answer::from("no path"));
+        assert_eq!(Day12::part_two(&input), Answer::from("no path"));
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day12>(40, 12, naive_answers);
+    }
+}
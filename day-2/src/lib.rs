@@ -0,0 +1,225 @@
+//! --- Day 2: Rock Paper Scissors ---
+
+use common::parse::{lines, words};
+use common::{Answer, ParseError, Rng, Solution};
+
+pub struct Day2;
+
+#[derive(Debug, Copy)]
+enum RockPaperScissor {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl Clone for RockPaperScissor {
+    fn clone(&self) -> RockPaperScissor {
+        *self
+    }
+}
+
+impl RockPaperScissor {
+    fn from_char(value: char) -> RockPaperScissor {
+        match value {
+            'A' => RockPaperScissor::Rock,
+            'B' => RockPaperScissor::Paper,
+            'C' => RockPaperScissor::Scissor,
+            'X' => RockPaperScissor::Rock,
+            'Y' => RockPaperScissor::Paper,
+            'Z' => RockPaperScissor::Scissor,
+            _ => panic!("Unknown value: {}", value),
+        }
+    }
+
+    fn to_score(value: RockPaperScissor) -> u32 {
+        match value {
+            RockPaperScissor::Rock => 1,
+            RockPaperScissor::Paper => 2,
+            RockPaperScissor::Scissor => 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum RockPaperScissorResult {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl RockPaperScissorResult {
+    fn from_char(value: char) -> RockPaperScissorResult {
+        match value {
+            'X' => RockPaperScissorResult::Loss,
+            'Y' => RockPaperScissorResult::Draw,
+            'Z' => RockPaperScissorResult::Win,
+            _ => panic!("Unknown value: {}", value),
+        }
+    }
+
+    fn to_score(value: RockPaperScissorResult) -> u32 {
+        match value {
+            RockPaperScissorResult::Loss => 0,
+            RockPaperScissorResult::Draw => 3,
+            RockPaperScissorResult::Win => 6,
+        }
+    }
+}
+
+fn line_to_char_set(file_content: &str, line: &str) -> Result<(char, char), ParseError> {
+    let [opponent, me] = words::<2>(file_content, line)?;
+
+    // The first column is the opponent's shape, the second one is mine or the result
+    let opponent_char = match opponent {
+        "A" | "B" | "C" => opponent.chars().next().unwrap(),
+        _ => return Err(ParseError::at(file_content, opponent, "A, B or C")),
+    };
+    let my_char = match me {
+        "X" | "Y" | "Z" => me.chars().next().unwrap(),
+        _ => return Err(ParseError::at(file_content, me, "X, Y or Z")),
+    };
+
+    Ok((opponent_char, my_char))
+}
+
+fn generate_strategy_guide(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+fn get_rock_paper_scissor_score(opponent: RockPaperScissor, me: RockPaperScissor) -> u32 {
+    let selection_score = RockPaperScissor::to_score(me);
+    let round_score = RockPaperScissorResult::to_score(rock_paper_scissor(opponent, me));
+
+    selection_score + round_score
+}
+
+fn rock_paper_scissor(opponent: RockPaperScissor, me: RockPaperScissor) -> RockPaperScissorResult {
+    match (opponent, me) {
+        (RockPaperScissor::Rock, RockPaperScissor::Rock) => RockPaperScissorResult::Draw,
+        (RockPaperScissor::Rock, RockPaperScissor::Paper) => RockPaperScissorResult::Win,
+        (RockPaperScissor::Rock, RockPaperScissor::Scissor) => RockPaperScissorResult::Loss,
+        (RockPaperScissor::Paper, RockPaperScissor::Rock) => RockPaperScissorResult::Loss,
+        (RockPaperScissor::Paper, RockPaperScissor::Paper) => RockPaperScissorResult::Draw,
+        (RockPaperScissor::Paper, RockPaperScissor::Scissor) => RockPaperScissorResult::Win,
+        (RockPaperScissor::Scissor, RockPaperScissor::Rock) => RockPaperScissorResult::Win,
+        (RockPaperScissor::Scissor, RockPaperScissor::Paper) => RockPaperScissorResult::Loss,
+        (RockPaperScissor::Scissor, RockPaperScissor::Scissor) => RockPaperScissorResult::Draw,
+    }
+}
+
+fn reverse_rock_paper_scissor(
+    opponent: RockPaperScissor,
+    my_result: RockPaperScissorResult,
+) -> RockPaperScissor {
+    match (opponent, my_result) {
+        (RockPaperScissor::Rock, RockPaperScissorResult::Loss) => RockPaperScissor::Scissor,
+        (RockPaperScissor::Rock, RockPaperScissorResult::Draw) => RockPaperScissor::Rock,
+        (RockPaperScissor::Rock, RockPaperScissorResult::Win) => RockPaperScissor::Paper,
+        (RockPaperScissor::Paper, RockPaperScissorResult::Loss) => RockPaperScissor::Rock,
+        (RockPaperScissor::Paper, RockPaperScissorResult::Draw) => RockPaperScissor::Paper,
+        (RockPaperScissor::Paper, RockPaperScissorResult::Win) => RockPaperScissor::Scissor,
+        (RockPaperScissor::Scissor, RockPaperScissorResult::Loss) => RockPaperScissor::Paper,
+        (RockPaperScissor::Scissor, RockPaperScissorResult::Draw) => RockPaperScissor::Scissor,
+        (RockPaperScissor::Scissor, RockPaperScissorResult::Win) => RockPaperScissor::Rock,
+    }
+}
+
+impl Solution for Day2 {
+    type Input = Vec<(char, char)>;
+
+    const DAY: u32 = 2;
+    const NAME: &'static str = "--- Day 2: Rock Paper Scissors ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "What would your total score be \
+        if everything goes exactly according to your strategy guide?",
+        "Following the Elf's instructions for the second column, \
+        what would your total score be if everything goes exactly according to your strategy guide?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        lines(file_content)
+            .map(|line| line_to_char_set(file_content, line))
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut total_score = 0;
+
+        for char_set in input {
+            total_score += get_rock_paper_scissor_score(
+                RockPaperScissor::from_char(char_set.0),
+                RockPaperScissor::from_char(char_set.1),
+            );
+        }
+
+        total_score.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut total_score = 0;
+
+        for char_set in input {
+            total_score += get_rock_paper_scissor_score(
+                RockPaperScissor::from_char(char_set.0),
+                reverse_rock_paper_scissor(
+                    RockPaperScissor::from_char(char_set.0),
+                    RockPaperScissorResult::from_char(char_set.1),
+                ),
+            );
+        }
+
+        total_score.into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_strategy_guide(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day2, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shape and outcome scores of both parts for each of the 9 possible rounds
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let mut scores = [0, 0];
+
+        for line in file_content.lines() {
+            let rounds = match line {
+                "A X" => [(1, 3), (3, 0)],
+                "A Y" => [(2, 6), (1, 3)],
+                "A Z" => [(3, 0), (2, 6)],
+                "B X" => [(1, 0), (1, 0)],
+                "B Y" => [(2, 3), (2, 3)],
+                "B Z" => [(3, 6), (3, 6)],
+                "C X" => [(1, 6), (2, 0)],
+                "C Y" => [(2, 0), (3, 3)],
+                "C Z" => [(3, 3), (1, 6)],
+                _ => panic!("Unknown round: {}", line),
+            };
+
+            for (score, (shape, outcome)) in scores.iter_mut().zip(rounds) {
+                *score += shape + outcome;
+            }
+        }
+
+        scores.map(Answer::from)
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day2>(100, 50, naive_answers);
+    }
+}
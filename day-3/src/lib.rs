@@ -0,0 +1,272 @@
+//! --- Day 3: Rucksack Reorganization ---
+
+use common::parse::lines;
+use common::{Answer, ParseError, Rng, Solution};
+
+pub struct Day3;
+
+/// Item types found in every one of `item_lists`, in the order of the first list.
+fn common_items(item_lists: &[&str]) -> Vec<char> {
+    let mut items: Vec<char> = Vec::new();
+
+    for char in item_lists[0].chars() {
+        if !items.contains(&char) && item_lists[1..].iter().all(|list| list.contains(char)) {
+            items.push(char);
+        }
+    }
+
+    items
+}
+
+fn compartments(rucksack: &str) -> [&str; 2] {
+    let (first_half, second_half) = rucksack.split_at(rucksack.len() / 2);
+    [first_half, second_half]
+}
+
+fn convert_char_to_value(char: char) -> u8 {
+    let result: u8;
+
+    if char.is_alphabetic() {
+        if char.is_lowercase() {
+            result = (char as u8) - 96;
+        } else {
+            result = (char as u8) - 38;
+        }
+    } else {
+        panic!("Char is not alphabetic!")
+    }
+
+    result
+}
+
+fn parse_rucksack(file_content: &str, line: &str) -> Result<String, ParseError> {
+    if let Some((index, char)) = line
+        .char_indices()
+        .find(|(_, char)| !char.is_ascii_alphabetic())
+    {
+        return Err(ParseError::at(
+            file_content,
+            &line[index..index + char.len_utf8()],
+            "an item type (a-z or A-Z)",
+        ));
+    }
+
+    // Both compartments hold the same number of items, at least one each
+    if line.is_empty() || !line.len().is_multiple_of(2) {
+        return Err(ParseError::at(
+            file_content,
+            line,
+            "an even number of items",
+        ));
+    }
+
+    if common_items(&compartments(line)).len() != 1 {
+        return Err(ParseError::at(
+            file_content,
+            line,
+            "exactly one item type in both compartments",
+        ));
+    }
+
+    Ok(line.to_string())
+}
+
+fn parse_rucksacks(file_content: &str) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<&str> = lines(file_content).collect();
+    let rucksacks = lines
+        .iter()
+        .map(|line| parse_rucksack(file_content, line))
+        .collect::<Result<Vec<String>, ParseError>>()?;
+
+    for group in lines.chunks(3) {
+        if group.len() < 3 {
+            return Err(ParseError::at(
+                file_content,
+                group[0],
+                "a group of 3 rucksacks",
+            ));
+        }
+        if common_items(group).len() != 1 {
+            return Err(ParseError::at(
+                file_content,
+                group[0],
+                "exactly one badge shared by the group of 3 rucksacks",
+            ));
+        }
+    }
+
+    Ok(rucksacks)
+}
+
+// Rucksack holding `badge` and items of `pool` only, both compartments sharing one item
+fn generate_rucksack(rng: &mut Rng, pool: &[char], badge: char) -> String {
+    let mut items: Vec<char> = pool.iter().copied().chain([badge]).collect();
+    rng.shuffle(&mut items);
+
+    let shared = items.pop().unwrap();
+    let (left_items, right_items) = items.split_at(items.len() / 2);
+    let length = rng.range(3..=16);
+    let mut compartments = [vec![shared], vec![shared]];
+
+    // The badge has to be in the rucksack, in whichever compartment it was sorted into
+    if shared != badge {
+        compartments[right_items.contains(&badge) as usize].push(badge);
+    }
+
+    for (compartment, side_items) in compartments.iter_mut().zip([left_items, right_items]) {
+        while compartment.len() < length {
+            compartment.push(*rng.choose(side_items));
+        }
+        rng.shuffle(compartment);
+    }
+
+    compartments.concat().into_iter().collect()
+}
+
+fn generate_rucksacks(size: usize, rng: &mut Rng) -> String {
+    let mut rucksacks = String::new();
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        let mut item_types: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        rng.shuffle(&mut item_types);
+        let badge = item_types.pop().unwrap();
+
+        // Each Elf of the group gets its own item types, so the badge is the only one they share
+        for pool in item_types.chunks(item_types.len() / 3) {
+            rucksacks += &generate_rucksack(rng, pool, badge);
+            rucksacks += "\n";
+        }
+    }
+
+    rucksacks
+}
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    const DAY: u32 = 3;
+    const NAME: &'static str = "--- Day 3: Rucksack Reorganization ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Find the item type that appears in both compartments of each rucksack. \
+        What is the sum of the priorities of those item types?",
+        "Find the item type that corresponds to the badges of each three-Elf group. \
+        What is the sum of the priorities of those item types?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+
+        for line in input {
+            // The parser checked there is exactly one
+            let common_char = common_items(&compartments(line))[0];
+            let value = convert_char_to_value(common_char);
+            sum += value as u32;
+        }
+
+        sum.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+
+        for group in input.chunks(3) {
+            let group: Vec<&str> = group.iter().map(String::as_str).collect();
+            let common_char = common_items(&group)[0];
+            let value = convert_char_to_value(common_char);
+            sum += value as u32;
+        }
+
+        sum.into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_rucksacks(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day3, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn priority(item: char) -> u32 {
+        ('a'..='z')
+            .chain('A'..='Z')
+            .position(|other| other == item)
+            .unwrap() as u32
+            + 1
+    }
+
+    // Every item type tried against every rucksack
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let rucksacks: Vec<&str> = file_content.lines().collect();
+        let item_types: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut sums = [0, 0];
+
+        for rucksack in &rucksacks {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            let shared: Vec<&char> = item_types
+                .iter()
+                .filter(|&&item| left.contains(item) && right.contains(item))
+                .collect();
+            assert_eq!(shared.len(), 1, "{} should share one item type", rucksack);
+            sums[0] += priority(*shared[0]);
+        }
+
+        for group in rucksacks.chunks(3) {
+            let badges: Vec<&char> = item_types
+                .iter()
+                .filter(|&&item| group.iter().all(|rucksack| rucksack.contains(item)))
+                .collect();
+            assert_eq!(badges.len(), 1, "{:?} should share one badge", group);
+            sums[1] += priority(*badges[0]);
+        }
+
+        sums.map(Answer::from)
+    }
+
+    #[test]
+    fn invalid_rucksacks() {
+        let example = "vJrwpWtwJgWrhcsFMMfFFhFp\n\
+            jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n\
+            PmmdzqPrVvPwwTWBwg\n";
+
+        assert_eq!(
+            Day3::parse("vJrwpWtwÉgWrhcsFMMfFFhFp\n").unwrap_err(),
+            ParseError::new(1, 9, "É", "an item type (a-z or A-Z)")
+        );
+        assert_eq!(
+            Day3::parse(&format!("{}\n", example)).unwrap_err(),
+            ParseError::new(4, 1, "", "an even number of items")
+        );
+        assert_eq!(
+            Day3::parse(&example[..58]).unwrap_err(),
+            ParseError::new(1, 1, "vJrwpWtwJgWrhcsFMMfFFhFp", "a group of 3 rucksacks")
+        );
+        assert_eq!(
+            Day3::parse("abcdef\nabcdef\nabcdef\n").unwrap_err(),
+            ParseError::new(1, 1, "abcdef", "exactly one item type in both compartments")
+        );
+        assert_eq!(
+            Day3::parse(&example.replace("PmmdzqPrVvPwwTWBwg", "PmmdzqPVvPwwTWBwgg")).unwrap_err(),
+            ParseError::new(
+                1,
+                1,
+                "vJrwpWtwJgWrhcsFMMfFFhFp",
+                "exactly one badge shared by the group of 3 rucksacks"
+            )
+        );
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day3>(100, 60, naive_answers);
+    }
+}
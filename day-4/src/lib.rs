@@ -0,0 +1,162 @@
+//! --- Day 4: Camp Cleanup ---
+
+use common::parse::{ints_exact, lines};
+use common::{Answer, ParseError, Rng, Solution};
+
+pub struct Day4;
+
+#[derive(Debug)]
+struct SectionRange {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug)]
+pub struct ElfPair {
+    elf_one: SectionRange,
+    elf_two: SectionRange,
+}
+
+fn convert_line_to_elf_pair(file_content: &str, line: &str) -> Result<ElfPair, ParseError> {
+    // "2-4,6-8" => [2, 4, 6, 8]
+    let sections = ints_exact::<u32, 4>(file_content, line)?;
+
+    let elf_pair: ElfPair = ElfPair {
+        elf_one: SectionRange {
+            start: sections[0],
+            end: sections[1],
+        },
+        elf_two: SectionRange {
+            start: sections[2],
+            end: sections[3],
+        },
+    };
+
+    Ok(elf_pair)
+}
+
+fn generate_section_range(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    let end = rng.range(start..=99);
+
+    format!("{}-{}", start, end)
+}
+
+fn generate_assignments(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{},{}\n",
+                generate_section_range(rng),
+                generate_section_range(rng)
+            )
+        })
+        .collect()
+}
+
+fn elves_section_range_full_overlap(elf_pair: &ElfPair) -> bool {
+    let one_contains_two = (elf_pair.elf_one.start <= elf_pair.elf_two.start)
+        && (elf_pair.elf_one.end >= elf_pair.elf_two.end);
+    let two_contains_one = (elf_pair.elf_one.start >= elf_pair.elf_two.start)
+        && (elf_pair.elf_one.end <= elf_pair.elf_two.end);
+
+    one_contains_two || two_contains_one
+}
+
+fn elves_section_range_partial_overlap(elf_pair: &ElfPair) -> bool {
+    (elf_pair.elf_one.end >= elf_pair.elf_two.start)
+        && (elf_pair.elf_one.start <= elf_pair.elf_two.end)
+}
+
+impl Solution for Day4 {
+    type Input = Vec<ElfPair>;
+
+    const DAY: u32 = 4;
+    const NAME: &'static str = "--- Day 4: Camp Cleanup ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "In how many assignment pairs does one range fully contain the other?",
+        "In how many assignment pairs do the ranges overlap?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        lines(file_content)
+            .map(|line| convert_line_to_elf_pair(file_content, line))
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut sum = 0;
+
+        for elf_pair in input {
+            let overlap = elves_section_range_full_overlap(elf_pair);
+            sum += overlap as u32;
+        }
+
+        sum.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut sum = 0;
+
+        for elf_pair in input {
+            let overlap = elves_section_range_partial_overlap(elf_pair);
+            sum += overlap as u32;
+        }
+
+        sum.into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_assignments(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day4, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    // Every section of both ranges listed
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let mut counts = [0, 0];
+
+        for line in file_content.lines() {
+            let ranges: Vec<BTreeSet<u32>> = line
+                .split(',')
+                .map(|range| {
+                    let (start, end) = range.split_once('-').unwrap();
+                    (start.parse().unwrap()..=end.parse().unwrap()).collect()
+                })
+                .collect();
+
+            counts[0] +=
+                (ranges[0].is_subset(&ranges[1]) || ranges[1].is_subset(&ranges[0])) as u32;
+            counts[1] += !ranges[0].is_disjoint(&ranges[1]) as u32;
+        }
+
+        counts.map(Answer::from)
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day4>(100, 50, naive_answers);
+    }
+
+    #[test]
+    fn nominal_full_overlap_is_partial_overlap() {
+        common::check_generated::<Day4>(100, 50, |_, input| {
+            for elf_pair in input {
+                assert!(
+                    !elves_section_range_full_overlap(elf_pair)
+                        || elves_section_range_partial_overlap(elf_pair),
+                    "{:?}",
+                    elf_pair
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,401 @@
+//! --- Day 5: Supply Stacks ---
+
+use common::parse::{blocks, ints_exact, lines};
+use common::{Answer, ParseError, Rng, Solution};
+
+pub struct Day5;
+
+#[derive(Debug, Clone)]
+pub struct Stack {
+    number: u32,
+    stack: Vec<char>,
+}
+
+impl Stack {
+    fn new(value: u32) -> Self {
+        Stack {
+            stack: Vec::new(),
+            number: value,
+        }
+    }
+
+    fn peek(&self) -> Option<&char> {
+        self.stack.last()
+    }
+}
+
+fn get_stack_index(stacks: &[Stack], number: u32) -> usize {
+    let mut found_index = 0;
+
+    for stack in stacks {
+        if number == stack.number {
+            break;
+        }
+        found_index += 1;
+    }
+
+    found_index
+}
+
+fn parse_crates(file_content: &str, vec: Vec<&str>) -> Result<Vec<Stack>, ParseError> {
+    let mut stack_primitive: Vec<String> = Vec::new();
+    let mut stacks: Vec<Stack> = Vec::new();
+    let mut stack_index = 0;
+    let width = vec.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    // Convert table text into vector of string stacks
+    for index in (1..width).step_by(4) {
+        let mut stack_str = String::new();
+
+        // Trailing spaces may have been trimmed from shorter lines
+        for line in &vec {
+            stack_str.push(line.chars().nth(index).unwrap_or(' '));
+        }
+
+        stack_primitive.push(stack_str);
+    }
+
+    // The last line holds the stack numbers
+    let numbers_line = vec[vec.len() - 1];
+
+    // Convert stack_primitive to fully functional stack
+    for stack_string in stack_primitive {
+        for index in 0..(stack_string.len()) {
+            if index == 0 {
+                // Numbers of ten and more take two of the three characters under the stack
+                let column = stack_index * 4;
+                let end = (column + 3).min(numbers_line.len());
+                let number = numbers_line
+                    .get(column..end)
+                    .map(str::trim)
+                    .unwrap_or(&numbers_line[numbers_line.len()..]);
+
+                match number.parse::<u32>() {
+                    Ok(number) => stacks.push(Stack::new(number)),
+                    Err(_) => return Err(ParseError::at(file_content, number, "a stack number")),
+                }
+            } else {
+                let char = stack_string.chars().rev().nth(index).unwrap();
+                if char != ' ' {
+                    stacks[stack_index].stack.push(char);
+                }
+            }
+        }
+        stack_index += 1;
+    }
+
+    Ok(stacks)
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    count: u32,
+    from: u32,
+    to: u32,
+}
+
+fn parse_instructions(
+    file_content: &str,
+    vec: Vec<&str>,
+    stacks: &[Stack],
+) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions: Vec<Instruction> = Vec::new();
+    // Heights of the stacks as the procedure goes, so no move takes more crates than there are
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.stack.len()).collect();
+
+    for line in vec {
+        // "move 1 from 2 to 1" => [1, 2, 1]
+        let values = ints_exact::<u32, 3>(file_content, line)?;
+
+        for number in [values[1], values[2]] {
+            if !stacks.iter().any(|stack| stack.number == number) {
+                return Err(ParseError::at(file_content, line, "existing stack numbers"));
+            }
+        }
+
+        let from_index = get_stack_index(stacks, values[1]);
+        let to_index = get_stack_index(stacks, values[2]);
+        let count = values[0] as usize;
+
+        if count > heights[from_index] {
+            return Err(ParseError::at(
+                file_content,
+                line,
+                format!(
+                    "a count of at most {}, the height of stack {}",
+                    heights[from_index], values[1]
+                ),
+            ));
+        }
+        heights[from_index] -= count;
+        heights[to_index] += count;
+
+        instructions.push(Instruction {
+            count: values[0],
+            from: values[1],
+            to: values[2],
+        });
+    }
+
+    Ok(instructions)
+}
+
+fn execute_rearrangement_procedure(
+    mut stacks: Vec<Stack>,
+    instructions: Vec<Instruction>,
+    multi_crates: bool,
+) -> Vec<Stack> {
+    let mut buffer_stack = Stack::new(0);
+
+    for instruction in instructions {
+        let from_index = get_stack_index(&stacks, instruction.from);
+        let to_index = get_stack_index(&stacks, instruction.to);
+
+        // The parser checked that every move has enough crates to take
+        if !multi_crates {
+            for _ in 0..instruction.count {
+                let payload = stacks[from_index]
+                    .stack
+                    .pop()
+                    .expect("Stack should hold the crates");
+                stacks[to_index].stack.push(payload);
+            }
+        } else {
+            for _ in 0..instruction.count {
+                let payload = stacks[from_index]
+                    .stack
+                    .pop()
+                    .expect("Stack should hold the crates");
+                buffer_stack.stack.push(payload);
+            }
+            while let Some(payload) = buffer_stack.stack.pop() {
+                stacks[to_index].stack.push(payload);
+            }
+        }
+    }
+
+    stacks
+}
+
+fn get_top_crates(stacks: &Vec<Stack>) -> String {
+    let mut string = String::new();
+
+    // An emptied stack has no crate on top, it adds nothing
+    for stack in stacks {
+        if let Some(&top) = stack.peek() {
+            string.push(top);
+        }
+    }
+
+    string
+}
+
+fn semantic_parsing(file_content: &str) -> Result<(Vec<Stack>, Vec<Instruction>), ParseError> {
+    let blocks = blocks(file_content);
+
+    if blocks.len() != 2 {
+        return Err(ParseError::at(
+            file_content,
+            &file_content[file_content.len()..],
+            "the crates and the rearrangement procedure, separated by a blank line",
+        ));
+    }
+
+    // Extract lines with crates and lines with instructions
+    let vec_lines_crates: Vec<&str> = lines(blocks[0]).collect();
+    let vec_lines_instructions: Vec<&str> = lines(blocks[1]).collect();
+
+    let stacks = parse_crates(file_content, vec_lines_crates)?;
+    let instructions = parse_instructions(file_content, vec_lines_instructions, &stacks)?;
+
+    Ok((stacks, instructions))
+}
+
+fn generate_drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(char) => format!("[{}]", char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing += &row.join(" ");
+        drawing += "\n";
+    }
+
+    let numbers: Vec<String> = (1..=stacks.len())
+        .map(|number| format!("{:^3}", number))
+        .collect();
+
+    drawing + &numbers.join(" ") + "\n"
+}
+
+fn generate_procedure(size: usize, rng: &mut Rng) -> String {
+    let stacks: Vec<Vec<char>> = (0..rng.range(2..=12))
+        .map(|_| {
+            (0..rng.range(2..=8))
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut procedure = generate_drawing(&stacks) + "\n";
+
+    // The procedure cannot be empty, it would read as a missing block
+    for _ in 0..size.max(1) {
+        // Stacks may be emptied, and have no top crate to read
+        let sources: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+        let from = *rng.choose(&sources);
+        let to = (from + rng.range(1..=heights.len() - 1)) % heights.len();
+        let count = rng.range(1..=heights[from]);
+
+        heights[from] -= count;
+        heights[to] += count;
+        procedure += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+    }
+
+    procedure
+}
+
+impl Solution for Day5 {
+    type Input = (Vec<Stack>, Vec<Instruction>);
+
+    const DAY: u32 = 5;
+    const NAME: &'static str = "--- Day 5: Supply Stacks ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "After the rearrangement procedure completes, what crate ends up on top of each stack?",
+        "After the rearrangement procedure completes, what crate ends up on top of each stack?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        semantic_parsing(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (stacks, instructions) = input.clone();
+
+        // Execute rearrangement (CrateMover 9000)
+        let stacks = execute_rearrangement_procedure(stacks, instructions, false);
+
+        get_top_crates(&stacks).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let (stacks, instructions) = input.clone();
+
+        // Execute rearrangement (CrateMover 9001)
+        let stacks = execute_rearrangement_procedure(stacks, instructions, true);
+
+        get_top_crates(&stacks).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_procedure(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day5, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Crates read at their column in the drawing, moved one at a time or all at once
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let (drawing, procedure) = file_content.split_once("\n\n").unwrap();
+        let rows: Vec<Vec<char>> = drawing.lines().map(|row| row.chars().collect()).collect();
+        let (numbers, crates) = rows.split_last().unwrap();
+        let count = String::from_iter(numbers).split_whitespace().count();
+
+        let mut stacks = vec![Vec::new(); count];
+        for row in crates.iter().rev() {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                if let Some(&char) = row.get(4 * i + 1).filter(|char| char.is_alphabetic()) {
+                    stack.push(char);
+                }
+            }
+        }
+
+        let mut answers = Vec::new();
+        for all_at_once in [false, true] {
+            let mut stacks = stacks.clone();
+
+            for line in procedure.lines() {
+                let words: Vec<usize> = line
+                    .split(' ')
+                    .filter_map(|word| word.parse().ok())
+                    .collect();
+                let (count, from, to) = (words[0], words[1] - 1, words[2] - 1);
+                let start = stacks[from].len() - count;
+                let mut moved: Vec<char> = stacks[from].drain(start..).collect();
+
+                if !all_at_once {
+                    moved.reverse();
+                }
+                stacks[to].extend(moved);
+            }
+
+            let tops: String = stacks.iter().filter_map(|stack| stack.last()).collect();
+            answers.push(Answer::from(tops));
+        }
+
+        answers.try_into().unwrap()
+    }
+
+    #[test]
+    fn invalid_procedure() {
+        let drawing = "    [D]\n[N] [C]\n 1   2\n\n";
+
+        let error = Day5::parse(&format!("{}move 3 from 1 to 2\n", drawing)).unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(
+            error.expected,
+            "a count of at most 1, the height of stack 1"
+        );
+
+        let error = Day5::parse(&format!(
+            "{}move 2 from 2 to 1\nmove 2 from 2 to 1\n",
+            drawing
+        ))
+        .unwrap_err();
+        assert_eq!(error.line, 6);
+
+        let error = Day5::parse(&format!("{}move 1 from 1 to 3\n", drawing)).unwrap_err();
+        assert_eq!(error.expected, "existing stack numbers");
+    }
+
+    #[test]
+    fn nominal_emptied_stack() {
+        let input = Day5::parse("    [D]\n[N] [C]\n 1   2\n\nmove 1 from 1 to 2\n").unwrap();
+        assert_eq!(Day5::part_one(&input), Answer::from("N"));
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day5>(100, 50, naive_answers);
+    }
+
+    #[test]
+    fn nominal_crate_count_conserved() {
+        let count = |stacks: &[Stack]| stacks.iter().map(|stack| stack.stack.len()).sum::<usize>();
+
+        common::check_generated::<Day5>(100, 50, |_, (stacks, instructions)| {
+            for multi_crates in [false, true] {
+                let moved = execute_rearrangement_procedure(
+                    stacks.clone(),
+                    instructions.clone(),
+                    multi_crates,
+                );
+
+                assert_eq!(count(&moved), count(stacks));
+                assert_eq!(moved.len(), stacks.len());
+            }
+        });
+    }
+}
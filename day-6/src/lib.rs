@@ -0,0 +1,193 @@
+//! --- Day 6: Tuning Trouble ---
+
+use common::parse::lines;
+use common::{Answer, Config, ConfigError, ParseError, Rng, Solution};
+use serde::Deserialize;
+
+pub struct Day6;
+
+/// Tunable constants, set in `config.toml` or with `--set`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Constants {
+    /// Distinct characters of a start-of-packet marker
+    packet_marker: usize,
+    /// Distinct characters of a start-of-message marker
+    message_marker: usize,
+}
+
+impl Default for Constants {
+    fn default() -> Self {
+        Constants {
+            packet_marker: 4,
+            message_marker: 14,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Datastream {
+    chars: Vec<char>,
+    constants: Constants,
+}
+
+fn check_unique_chars(char_vec: Vec<char>) -> bool {
+    let mut unique_chars = true;
+
+    'outer: for i in 0..char_vec.len() {
+        for j in (i + 1)..char_vec.len() {
+            if char_vec[i] == char_vec[j] {
+                unique_chars = false;
+                break 'outer;
+            }
+        }
+    }
+
+    unique_chars
+}
+
+fn marker_seeker(char_vec: &[char], char_count: usize) -> u32 {
+    let mut result: u32 = 0;
+
+    for i in (char_count - 1)..(char_vec.len()) {
+        let mut marker_vec: Vec<char> = Vec::new();
+
+        for j in 0..char_count {
+            marker_vec.push(char_vec[i + j + 1 - char_count]);
+        }
+
+        let marker_found = check_unique_chars(marker_vec);
+
+        if marker_found {
+            result = (i as u32) + 1;
+            break;
+        }
+    }
+
+    result
+}
+
+fn random_letters(rng: &mut Rng, letters: &[char], count: usize) -> Vec<char> {
+    (0..count).map(|_| *rng.choose(letters)).collect()
+}
+
+fn generate_datastream(size: usize, rng: &mut Rng) -> String {
+    let length = size.max(30);
+    let message_start = rng.range(length / 4..=length - 14);
+    let mut alphabet: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut alphabet);
+
+    // Noise of 3 letters holds no marker, then 8 letters hold packet markers only,
+    // until a start-of-message marker is planted
+    let mut datastream = random_letters(rng, &alphabet[..3], message_start / 2);
+    datastream.extend(random_letters(
+        rng,
+        &alphabet[..8],
+        message_start - message_start / 2,
+    ));
+    datastream.extend(&alphabet[..14]);
+    datastream.extend(random_letters(rng, &alphabet, length - message_start - 14));
+
+    datastream.into_iter().chain(['\n']).collect()
+}
+
+impl Solution for Day6 {
+    type Input = Datastream;
+
+    const DAY: u32 = 6;
+    const NAME: &'static str = "--- Day 6: Tuning Trouble ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "How many characters need to be processed before the first start-of-packet marker is detected?",
+        "How many characters need to be processed before the first start-of-message marker is detected?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        Ok(Datastream {
+            chars: lines(file_content).next().unwrap_or("").chars().collect(),
+            constants: Constants::default(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        // Search start-of-packet marker
+        let result = marker_seeker(&input.chars, input.constants.packet_marker);
+
+        result.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        // Search start-of-message marker
+        let result = marker_seeker(&input.chars, input.constants.message_marker);
+
+        result.into()
+    }
+
+    fn configure(input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        let constants: Constants = config.to_typed()?;
+        if constants.packet_marker == 0 || constants.message_marker == 0 {
+            return Err(ConfigError::new(
+                "Markers should be at least 1 character long",
+            ));
+        }
+
+        input.constants = constants;
+        Ok(())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_datastream(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(
+    Day6,
+    example_1 => 1,
+    example_2 => 2,
+    example_3 => 3,
+    example_4 => 4,
+    example_5 => 5,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // Characters read up to the end of the first window of distinct ones
+    fn naive_marker(datastream: &str, length: usize) -> Answer {
+        let chars: Vec<char> = datastream.trim_end().chars().collect();
+        let start = chars
+            .windows(length)
+            .position(|window| window.iter().collect::<HashSet<_>>().len() == length)
+            .unwrap();
+
+        (start + length).into()
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day6>(100, 500, |file_content| {
+            [
+                naive_marker(file_content, 4),
+                naive_marker(file_content, 14),
+            ]
+        });
+    }
+
+    #[test]
+    fn nominal_configure() {
+        let mut input = Day6::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        let mut config = Config::default();
+        config.set("packet_marker=2").unwrap();
+        config.set("message_marker=5").unwrap();
+        Day6::configure(&mut input, &config).unwrap();
+
+        assert_eq!(Day6::part_one(&input), Answer::from(2));
+        assert_eq!(Day6::part_two(&input), Answer::from(8));
+
+        config.set("packet_marker=0").unwrap();
+        assert!(Day6::configure(&mut input, &config).is_err());
+    }
+}
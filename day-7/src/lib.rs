@@ -0,0 +1,689 @@
+//! --- Day 7: No Space Left On Device ---
+
+use common::parse::{lines, number, words};
+use common::{Answer, Config, ConfigError, ParseError, Rng, Solution};
+use serde::Deserialize;
+
+pub struct Day7;
+
+/// Tunable constants, set in `config.toml` or with `--set`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Constants {
+    /// Size of the whole disk
+    total_space: u64,
+    /// Unused space the update needs
+    needed_space: u64,
+    /// Largest directory summed by part one
+    small_directory: u64,
+}
+
+impl Default for Constants {
+    fn default() -> Self {
+        Constants {
+            total_space: 70_000_000,
+            needed_space: 30_000_000,
+            small_directory: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct File {
+    name: String,
+    size: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Directory {
+    name: String,
+    directories: Vec<Directory>,
+    files: Vec<File>,
+    total_size: u64,
+    processed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    root: Directory,
+    constants: Constants,
+}
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    ChangeDirRoot,
+    ChangeDir,
+    ChangeDirUp,
+    ListFiles,
+    DirInfo,
+    FileInfo,
+}
+
+#[derive(Debug)]
+struct Command {
+    action: Action,
+    param: String,
+    /// Size of the file of a [`Action::FileInfo`], 0 otherwise
+    size: u64,
+}
+
+fn line_parser(file_content: &str, line: &str) -> Result<Command, ParseError> {
+    let (action, param, size) = if let Some(param) = line.strip_prefix("$ cd ") {
+        match param {
+            "/" => (Action::ChangeDirRoot, param, 0),
+            ".." => (Action::ChangeDirUp, "", 0),
+            _ => (Action::ChangeDir, param, 0),
+        }
+    } else if line == "$ ls" {
+        (Action::ListFiles, "", 0)
+    } else if let Some(param) = line.strip_prefix("dir ") {
+        (Action::DirInfo, param, 0)
+    } else if line.starts_with(|char: char| char.is_ascii_digit()) {
+        // "14848514 b.txt" => size and name
+        let [size, name] = words::<2>(file_content, line)?;
+
+        (Action::FileInfo, name, number::<u64>(file_content, size)?)
+    } else {
+        return Err(ParseError::at(
+            file_content,
+            line,
+            "a command ($ cd, $ ls) or a listing (dir, file size)",
+        ));
+    };
+
+    Ok(Command {
+        action,
+        param: param.to_string(),
+        size,
+    })
+}
+
+fn get_dir<'a>(parent_dir: &'a mut Directory, dir_name: &str) -> Result<&'a mut Directory, String> {
+    for dir in &mut (parent_dir.directories) {
+        if dir.name == dir_name {
+            return Ok(dir);
+        }
+    }
+
+    Err("Directory NOT found!".to_string())
+}
+
+fn get_cwd<'a>(dir_tree: &'a mut Directory, dir_stack: &[String]) -> &'a mut Directory {
+    let mut cwd = dir_tree;
+
+    // Every directory of the stack was checked to exist before entering it
+    for path in dir_stack.iter().skip(1) {
+        cwd = get_dir(cwd, path).expect("Directory should have been listed");
+    }
+
+    cwd
+}
+
+fn has_dir(parent_dir: &mut Directory, dir_name: &str) -> bool {
+    get_dir(parent_dir, dir_name).is_ok()
+}
+
+fn add_dir(parent_dir: &mut Directory, dir: Directory) {
+    parent_dir.directories.push(dir);
+}
+
+// A directory listed again lists the same files, which only count once
+fn add_file(parent_dir: &mut Directory, file: File) {
+    if !parent_dir.files.iter().any(|known| known.name == file.name) {
+        parent_dir.files.push(file);
+    }
+}
+
+fn print_dir(dir: &Directory, level: i32) {
+    let cwd = dir;
+    let tab = 4;
+    let spaces = (tab * level) as usize;
+
+    for dir in &(cwd.directories) {
+        print!("{}", " ".repeat(spaces));
+        println!("- {} (dir, size={})", dir.name, dir.total_size);
+        print_dir(dir, level + 1);
+    }
+
+    for file in &(cwd.files) {
+        print!("{}", " ".repeat(spaces));
+        println!("- {} (file, size={})", file.name, file.size);
+    }
+}
+
+#[allow(dead_code)]
+fn print_dir_tree(dir_tree: &Directory) {
+    let cwd = dir_tree;
+
+    println!("- {} (dir, size={})", cwd.name, cwd.total_size);
+    print_dir(cwd, 1);
+}
+
+fn construct_dir_tree(file_content: &str) -> Result<Directory, ParseError> {
+    let lines = lines(file_content);
+    let mut dir_tree = Directory::default();
+    let mut dir_stack: Vec<String> = Vec::new();
+
+    for line in lines {
+        let command = line_parser(file_content, line)?;
+
+        // Nothing is known of the working directory until the transcript enters the root
+        if dir_stack.is_empty() && command.action != Action::ChangeDirRoot {
+            return Err(ParseError::at(
+                file_content,
+                line,
+                "`$ cd /` before any other line",
+            ));
+        }
+
+        match command.action {
+            Action::ChangeDirRoot => {
+                dir_stack = vec![command.param.clone()];
+                dir_tree.name = command.param.clone();
+            }
+            Action::ChangeDir => {
+                let cwd: &mut Directory = get_cwd(&mut dir_tree, &dir_stack);
+                if !has_dir(cwd, &(command.param)) {
+                    return Err(ParseError::at(
+                        file_content,
+                        line,
+                        "a directory listed by `$ ls` first",
+                    ));
+                }
+                dir_stack.push(command.param.clone());
+            }
+            Action::ChangeDirUp => {
+                if dir_stack.len() == 1 {
+                    return Err(ParseError::at(
+                        file_content,
+                        line,
+                        "a directory to leave, not the root",
+                    ));
+                }
+                dir_stack.pop();
+            }
+            Action::ListFiles => {}
+            Action::DirInfo => {
+                let dir = Directory {
+                    name: command.param.clone(),
+                    ..Default::default()
+                };
+                let cwd: &mut Directory = get_cwd(&mut dir_tree, &dir_stack);
+
+                if !has_dir(cwd, &(dir.name)) {
+                    add_dir(cwd, dir);
+                }
+            }
+            Action::FileInfo => {
+                let cwd: &mut Directory = get_cwd(&mut dir_tree, &dir_stack);
+                let file = File {
+                    name: command.param,
+                    size: command.size,
+                };
+                add_file(cwd, file);
+            }
+        }
+    }
+
+    Ok(dir_tree)
+}
+
+fn generate_name(rng: &mut Rng, taken: &[String]) -> String {
+    loop {
+        let name: String = (0..rng.range(1..=8))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+fn generate_ls(dir: &Directory, transcript: &mut String) {
+    *transcript += "$ ls\n";
+
+    for sub_dir in &dir.directories {
+        *transcript += &format!("dir {}\n", sub_dir.name);
+    }
+    for file in &dir.files {
+        *transcript += &format!("{} {}\n", file.size, file.name);
+    }
+}
+
+// `$ ls` of `dir` followed by a visit of each of its directories, sometimes listing it
+// again on the way back like puzzle inputs may
+fn generate_listing(dir: &Directory, transcript: &mut String, rng: &mut Rng) {
+    generate_ls(dir, transcript);
+
+    for sub_dir in &dir.directories {
+        *transcript += &format!("$ cd {}\n", sub_dir.name);
+        generate_listing(sub_dir, transcript, rng);
+        *transcript += "$ cd ..\n";
+    }
+
+    if rng.chance(0.2) {
+        generate_ls(dir, transcript);
+    }
+}
+
+fn generate_transcript(size: usize, rng: &mut Rng) -> String {
+    // Directories are added under random earlier ones, as paths of child indexes
+    let mut dir_tree = Directory {
+        name: "/".to_string(),
+        ..Default::default()
+    };
+    let mut paths: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut files = Vec::new();
+
+    for _ in 0..size {
+        let path = rng.choose(&paths).clone();
+        let parent = path
+            .iter()
+            .fold(&mut dir_tree, |dir, &index| &mut dir.directories[index]);
+        let taken: Vec<String> = parent
+            .directories
+            .iter()
+            .map(|dir| dir.name.clone())
+            .collect();
+
+        parent.directories.push(Directory {
+            name: generate_name(rng, &taken),
+            ..Default::default()
+        });
+        paths.push([path, vec![parent.directories.len() - 1]].concat());
+    }
+
+    // Files use more of the disk than the update leaves free, without filling it
+    let disk_usage = rng.range(40_000_001..=69_999_999) as u64;
+    let mut used = 0;
+
+    for path in &paths {
+        for _ in 0..rng.range(0..=4) {
+            // Mostly small files, so some directories stay under the part one limit
+            let size = (rng.range(1..=300_000) / rng.range(1..=20)) as u64;
+            if used + size < disk_usage {
+                used += size;
+                files.push((path, size));
+            }
+        }
+    }
+
+    // A few large files anywhere make up the rest
+    for piece in (0..4).rev() {
+        let size = if piece == 0 {
+            disk_usage - used
+        } else {
+            rng.range(0..=(disk_usage - used) as usize) as u64
+        };
+
+        if size > 0 {
+            used += size;
+            files.push((rng.choose(&paths), size));
+        }
+    }
+
+    for (path, size) in files {
+        let dir = path
+            .iter()
+            .fold(&mut dir_tree, |dir, &index| &mut dir.directories[index]);
+        let taken: Vec<String> = dir
+            .files
+            .iter()
+            .map(|file| file.name.split('.').next().unwrap().to_string())
+            .collect();
+        let stem = generate_name(rng, &taken);
+        let extension = rng.choose(&["txt", "dat", "log", "bin"]);
+
+        dir.files.push(File {
+            name: format!("{}.{}", stem, extension),
+            size,
+        });
+    }
+
+    let mut transcript = "$ cd /\n".to_string();
+    generate_listing(&dir_tree, &mut transcript, rng);
+
+    transcript
+}
+
+/// ALGO
+/// Does the root dir was exit?
+///     NO:
+///         does this dir has other dirs with [processed = false] dir?
+///             YES:
+///                 enter that dir
+///             NO:
+///                 does this dir has other dirs?
+///                     sum their sizes and add to this dir size
+///                 does this dir has files?
+///                     sum their sizes and add to this dir size
+///                 exit this dir
+///             REPEAT ALGO
+///     YES:
+///         EXIT ALGO
+fn compute_dirs_total_sizes(dir_tree: &mut Directory) {
+    let mut dir_stack: Vec<String> = vec!["/".to_string()];
+    let mut cwd: &mut Directory = get_cwd(dir_tree, &dir_stack);
+
+    while !dir_stack.is_empty() {
+        let mut sub_dirs_processed = true;
+
+        for dir in &(cwd.directories) {
+            if !dir.processed {
+                dir_stack.push(dir.name.clone());
+                cwd = get_cwd(dir_tree, &dir_stack);
+                sub_dirs_processed = false;
+                break;
+            }
+        }
+
+        if sub_dirs_processed {
+            for dir in &(cwd.directories) {
+                cwd.total_size += dir.total_size;
+            }
+
+            for file in &(cwd.files) {
+                cwd.total_size += file.size;
+            }
+
+            cwd.processed = true;
+            dir_stack.pop();
+            cwd = get_cwd(dir_tree, &dir_stack);
+        }
+    }
+}
+
+fn clear_processed_flags(dir_tree: &mut Directory) {
+    let mut dir_stack: Vec<String> = vec!["/".to_string()];
+    let mut cwd: &mut Directory = get_cwd(dir_tree, &dir_stack);
+
+    while !dir_stack.is_empty() {
+        let mut sub_dirs_processed = true;
+
+        for dir in &(cwd.directories) {
+            if dir.processed {
+                dir_stack.push(dir.name.clone());
+                cwd = get_cwd(dir_tree, &dir_stack);
+                sub_dirs_processed = false;
+                break;
+            }
+        }
+
+        if sub_dirs_processed {
+            cwd.processed = false;
+            dir_stack.pop();
+            cwd = get_cwd(dir_tree, &dir_stack);
+        }
+    }
+}
+
+fn sum_all_dirs_sizes(dir_tree: &mut Directory, size_limit: u64) -> u64 {
+    // The walk below only sums subdirectories, the root counts as well
+    let mut sum = if dir_tree.total_size <= size_limit {
+        dir_tree.total_size
+    } else {
+        0
+    };
+    let mut dir_stack: Vec<String> = vec!["/".to_string()];
+    let mut cwd: &mut Directory = get_cwd(dir_tree, &dir_stack);
+
+    clear_processed_flags(cwd);
+
+    while !dir_stack.is_empty() {
+        let mut sub_dirs_processed = true;
+
+        for dir in &(cwd.directories) {
+            if !dir.processed {
+                dir_stack.push(dir.name.clone());
+                cwd = get_cwd(dir_tree, &dir_stack);
+                sub_dirs_processed = false;
+                break;
+            }
+        }
+
+        if sub_dirs_processed {
+            for dir in &(cwd.directories) {
+                if dir.total_size <= size_limit {
+                    sum += dir.total_size;
+                }
+            }
+
+            cwd.processed = true;
+            dir_stack.pop();
+            cwd = get_cwd(dir_tree, &dir_stack);
+        }
+    }
+
+    sum
+}
+
+fn get_smallest_dir_size_with_limit(dir_tree: &mut Directory, size_limit: u64) -> u64 {
+    let mut current_size = dir_tree.total_size;
+    let mut dir_stack: Vec<String> = vec!["/".to_string()];
+    let mut cwd: &mut Directory = get_cwd(dir_tree, &dir_stack);
+
+    clear_processed_flags(cwd);
+
+    while !dir_stack.is_empty() {
+        let mut sub_dirs_processed = true;
+
+        for dir in &(cwd.directories) {
+            if !dir.processed {
+                dir_stack.push(dir.name.clone());
+                cwd = get_cwd(dir_tree, &dir_stack);
+                sub_dirs_processed = false;
+                break;
+            }
+        }
+
+        if sub_dirs_processed {
+            for dir in &(cwd.directories) {
+                if (dir.total_size >= size_limit) && (dir.total_size < current_size) {
+                    current_size = dir.total_size;
+                }
+            }
+
+            cwd.processed = true;
+            dir_stack.pop();
+            cwd = get_cwd(dir_tree, &dir_stack);
+        }
+    }
+
+    current_size
+}
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+
+    const DAY: u32 = 7;
+    const NAME: &'static str = "--- Day 7: No Space Left On Device ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Find all of the directories with a total size of at most 100000. \
+        What is the sum of the total sizes of those directories?",
+        "Find the smallest directory that, if deleted, would free up enough space on the filesystem to run the update. \
+        What is the total size of that directory?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        let mut dir_tree = construct_dir_tree(file_content)?;
+        compute_dirs_total_sizes(&mut dir_tree);
+
+        Ok(FileSystem {
+            root: dir_tree,
+            constants: Constants::default(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut dir_tree = input.root.clone();
+
+        sum_all_dirs_sizes(&mut dir_tree, input.constants.small_directory).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let total_disk_size = input.constants.total_space;
+        let needed_space = input.constants.needed_space;
+
+        let mut dir_tree = input.root.clone();
+        let space_to_be_emptied = dir_tree
+            .total_size
+            .saturating_sub(total_disk_size - needed_space);
+
+        get_smallest_dir_size_with_limit(&mut dir_tree, space_to_be_emptied).into()
+    }
+
+    fn configure(input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        let constants: Constants = config.to_typed()?;
+        if constants.needed_space > constants.total_space {
+            return Err(ConfigError::new(
+                "The needed space should fit on the disk, within total_space",
+            ));
+        }
+
+        input.constants = constants;
+        Ok(())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_transcript(size, rng))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day7, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // Every directory, keyed by its path, and the size of every distinct file, keyed by
+    // its path and name
+    #[allow(clippy::type_complexity)]
+    fn listed(file_content: &str) -> (Vec<Vec<&str>>, HashMap<Vec<&str>, u64>) {
+        let mut cwd: Vec<&str> = Vec::new();
+        let mut dirs = vec![Vec::new()];
+        let mut files = HashMap::new();
+
+        for line in file_content.lines() {
+            match line.split(' ').collect::<Vec<_>>()[..] {
+                ["$", "cd", "/"] => cwd.clear(),
+                ["$", "cd", ".."] => {
+                    cwd.pop();
+                }
+                ["$", "cd", name] => cwd.push(name),
+                ["$", "ls"] => {}
+                ["dir", name] => dirs.push([&cwd[..], &[name]].concat()),
+                [size, name] => {
+                    files.insert([&cwd[..], &[name]].concat(), size.parse().unwrap());
+                }
+                _ => panic!("Unknown line: {}", line),
+            }
+        }
+
+        (dirs, files)
+    }
+
+    // Size of every directory, each file added to all its parents
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let (dirs, files) = listed(file_content);
+        let mut sizes: HashMap<Vec<&str>, u64> = dirs.into_iter().map(|dir| (dir, 0)).collect();
+
+        for (path, size) in files {
+            for depth in 0..path.len() {
+                *sizes.get_mut(&path[..depth]).unwrap() += size;
+            }
+        }
+
+        let needed = sizes[&Vec::new()] - 40_000_000;
+
+        [
+            sizes
+                .values()
+                .filter(|&&size| size <= 100_000)
+                .sum::<u64>()
+                .into(),
+            sizes
+                .values()
+                .filter(|&&size| size >= needed)
+                .min()
+                .unwrap()
+                .to_owned()
+                .into(),
+        ]
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day7>(100, 60, naive_answers);
+    }
+
+    #[test]
+    fn nominal_root_size_is_sum_of_files() {
+        common::check_generated::<Day7>(100, 60, |file_content, file_system| {
+            assert_eq!(
+                file_system.root.total_size,
+                listed(file_content).1.values().sum::<u64>()
+            );
+        });
+    }
+
+    #[test]
+    fn invalid_transcript() {
+        let error = Day7::parse("$ ls\n1000 b.txt\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (1, "`$ cd /` before any other line")
+        );
+
+        let error = Day7::parse("$ cd /\n$ cd a\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a directory listed by `$ ls` first")
+        );
+
+        let error = Day7::parse("$ cd /\n$ cd ..\n").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = Day7::parse("$ cd /\n$ ls\n1000\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (3, "2 words"));
+
+        // Entering the root again from anywhere is fine
+        let input = Day7::parse("$ cd /\n$ ls\ndir a\n$ cd a\n$ cd /\n$ ls\n5 b\n").unwrap();
+        assert_eq!(input.root.total_size, 5);
+    }
+
+    #[test]
+    fn nominal_configure() {
+        let file_content = "$ cd /\n$ ls\ndir a\n1000 b.txt\n$ cd a\n$ ls\n300 c.txt\n";
+        let mut input = Day7::parse(file_content).unwrap();
+        let mut config = Config::default();
+        config.set("small_directory=500").unwrap();
+        config.set("total_space=2000").unwrap();
+        config.set("needed_space=1500").unwrap();
+        Day7::configure(&mut input, &config).unwrap();
+
+        // Only `a` is small, and 800 more units are needed
+        assert_eq!(Day7::part_one(&input), Answer::from(300));
+        assert_eq!(Day7::part_two(&input), Answer::from(1300));
+
+        config.set("needed_space=3000").unwrap();
+        assert!(Day7::configure(&mut input, &config).is_err());
+    }
+
+    #[test]
+    fn nominal_small_root_and_large_files() {
+        // The root is a directory of at most 100000 as well
+        let input =
+            Day7::parse("$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt\n").unwrap();
+        assert_eq!(Day7::part_one(&input), Answer::from(150 + 50));
+
+        // Sizes past 4 GB
+        let input = Day7::parse("$ cd /\n$ ls\n3000000000 b.txt\n3000000000 c.txt\n").unwrap();
+        assert_eq!(input.root.total_size, 6_000_000_000);
+        assert_eq!(Day7::part_two(&input), Answer::from(6_000_000_000u64));
+    }
+}
@@ -0,0 +1,337 @@
+//! --- Day 8: Treetop Tree House ---
+
+use common::geom::Dir4;
+use common::viz::{Cell, Colour, Frame, Render};
+use common::{Answer, Grid, Implementation, ParseError, Rng, Solution};
+
+pub struct Day8;
+
+#[derive(Debug, Clone)]
+struct Tree {
+    height: u8,
+    visible: bool,
+    scenic_score: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Forest {
+    grid: Grid<Tree>,
+}
+
+fn parse_input_file(file_content: &str) -> Result<Forest, ParseError> {
+    let grid = Grid::parse(file_content, "a tree height (0-9)", |char| {
+        let height = char.to_digit(10)?;
+
+        Some(Tree {
+            height: height as u8,
+            visible: false,
+            scenic_score: 0,
+        })
+    })?;
+
+    Ok(Forest { grid })
+}
+
+fn generate_forest(size: usize, rng: &mut Rng) -> String {
+    let side = size.max(1);
+
+    (0..side)
+        .map(|_| {
+            let row: String = (0..side)
+                .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+fn check_visible_trees(forest: &mut Forest) {
+    for position in forest.grid.positions() {
+        let current_height = forest.grid[position].height;
+
+        // Visible when every tree up to an edge is shorter, always true on the edges
+        let visible = Dir4::ALL.into_iter().any(|direction| {
+            forest
+                .grid
+                .ray(position, direction.vector().into())
+                .all(|other| forest.grid[other].height < current_height)
+        });
+
+        forest.grid[position].visible = visible;
+    }
+}
+
+fn count_visible_trees(forest: &Forest) -> u32 {
+    forest.grid.iter().filter(|tree| tree.visible).count() as u32
+}
+
+fn tree_cell(tree: &Tree, colour: Colour) -> Cell {
+    Cell::new(char::from_digit(tree.height as u32, 10).unwrap(), colour)
+}
+
+// Visible trees in green, hidden ones in grey
+fn visibility_frame(forest: &Forest) -> Frame {
+    let cells = forest.grid.map(|tree| {
+        tree_cell(
+            tree,
+            if tree.visible {
+                Colour::Green
+            } else {
+                Colour::Grey
+            },
+        )
+    });
+
+    Frame {
+        cells,
+        caption: format!("{} visible trees", count_visible_trees(forest)),
+        focus: None,
+    }
+}
+
+fn compute_scenic_scores(forest: &mut Forest) {
+    for position in forest.grid.positions() {
+        let current_height = forest.grid[position].height;
+        let mut scenic_score = 1;
+
+        for direction in Dir4::ALL {
+            let mut tree_count = 0;
+
+            for other in forest.grid.ray(position, direction.vector().into()) {
+                tree_count += 1;
+                if current_height <= forest.grid[other].height {
+                    break;
+                }
+            }
+
+            scenic_score *= tree_count;
+        }
+
+        forest.grid[position].scenic_score = scenic_score;
+    }
+}
+
+fn get_max_scenic_score(forest: &Forest) -> u32 {
+    forest
+        .grid
+        .iter()
+        .map(|tree| tree.scenic_score)
+        .max()
+        .unwrap_or(0)
+}
+
+// Looking along every row and column both ways, a stack keeps the trees still in sight
+// from the current one, by decreasing height: the trees it hides are popped for good.
+// Returns the visibility and scenic score of every tree in a single pass per line.
+fn stack_sweeps(forest: &Forest) -> (Grid<bool>, Grid<u32>) {
+    let (width, height) = (forest.grid.width(), forest.grid.height());
+    let mut visible = Grid::new(width, height, false);
+    let mut scenic_scores = Grid::new(width, height, 1);
+
+    let rows = (0..height).map(|y| (0..width).map(|x| (x, y)).collect::<Vec<_>>());
+    let columns = (0..width).map(|x| (0..height).map(|y| (x, y)).collect::<Vec<_>>());
+
+    for mut line in rows.chain(columns) {
+        for _ in 0..2 {
+            let mut stack: Vec<usize> = Vec::new();
+
+            for (i, &position) in line.iter().enumerate() {
+                let current_height = forest.grid[position].height;
+
+                while let Some(&top) = stack.last() {
+                    if forest.grid[line[top]].height >= current_height {
+                        break;
+                    }
+                    stack.pop();
+                }
+
+                // Nothing left in sight means every tree up to the edge is shorter
+                match stack.last() {
+                    Some(&blocking) => scenic_scores[position] *= (i - blocking) as u32,
+                    None => {
+                        visible[position] = true;
+                        scenic_scores[position] *= i as u32;
+                    }
+                }
+                stack.push(i);
+            }
+
+            line.reverse();
+        }
+    }
+
+    (visible, scenic_scores)
+}
+
+fn count_visible_trees_stack(forest: &Forest) -> Answer {
+    let (visible, _) = stack_sweeps(forest);
+    (visible.iter().filter(|&&visible| visible).count() as u32).into()
+}
+
+fn max_scenic_score_stack(forest: &Forest) -> Answer {
+    let (_, scenic_scores) = stack_sweeps(forest);
+    scenic_scores.iter().copied().max().unwrap_or(0).into()
+}
+
+// The tree with the highest scenic score in red, with the trees it sees in yellow
+fn scenic_frame(forest: &Forest) -> Frame {
+    let mut cells = forest.grid.map(|tree| tree_cell(tree, Colour::Grey));
+    let Some(best) = forest
+        .grid
+        .positions()
+        .max_by_key(|&position| forest.grid[position].scenic_score)
+    else {
+        return Frame {
+            cells,
+            caption: String::new(),
+            focus: None,
+        };
+    };
+    let best_height = forest.grid[best].height;
+
+    for direction in Dir4::ALL {
+        for other in forest.grid.ray(best, direction.vector().into()) {
+            cells[other].colour = Some(Colour::Yellow);
+            if best_height <= forest.grid[other].height {
+                break;
+            }
+        }
+    }
+    cells[best].colour = Some(Colour::Red);
+
+    Frame {
+        cells,
+        caption: format!(
+            "Highest scenic score {} at {:?}",
+            forest.grid[best].scenic_score, best
+        ),
+        focus: Some(best),
+    }
+}
+
+impl Solution for Day8 {
+    type Input = Forest;
+
+    const DAY: u32 = 8;
+    const NAME: &'static str = "--- Day 8: Treetop Tree House ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Consider your map; how many trees are visible from outside the grid?",
+        "Consider each tree on your map. \
+        What is the highest scenic score possible for any tree?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        parse_input_file(file_content)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut forest = input.clone();
+        check_visible_trees(&mut forest);
+        count_visible_trees(&forest).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut forest = input.clone();
+        compute_scenic_scores(&mut forest);
+        get_max_scenic_score(&forest).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_forest(size, rng))
+    }
+
+    fn alternatives() -> Vec<Implementation<Self::Input>> {
+        vec![
+            Implementation {
+                name: "stack",
+                part: 1,
+                solve: count_visible_trees_stack,
+            },
+            Implementation {
+                name: "stack",
+                part: 2,
+                solve: max_scenic_score_stack,
+            },
+        ]
+    }
+
+    fn visualize(input: &Self::Input, part: usize) -> Option<Box<dyn Render + '_>> {
+        let frame = if part == 1 {
+            let mut forest = input.clone();
+            check_visible_trees(&mut forest);
+            visibility_frame(&forest)
+        } else {
+            let mut forest = input.clone();
+            compute_scenic_scores(&mut forest);
+            scenic_frame(&forest)
+        };
+
+        Some(Box::new(std::iter::once(frame)))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day8, example_1 => 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every tree looking in the 4 directions with plain index loops
+    fn naive_answers(file_content: &str) -> [Answer; 2] {
+        let rows: Vec<Vec<u32>> = file_content
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|char| char.to_digit(10).unwrap())
+                    .collect()
+            })
+            .collect();
+        let (height, width) = (rows.len() as i32, rows[0].len() as i32);
+        let mut visible = 0;
+        let mut best_score = 0;
+
+        for y in 0..height {
+            for x in 0..width {
+                let tree = rows[y as usize][x as usize];
+                let mut seen_from_edge = false;
+                let mut score = 1;
+
+                for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let (mut other_x, mut other_y) = (x + dx, y + dy);
+                    let mut distance = 0;
+                    let mut blocked = false;
+
+                    while (0..width).contains(&other_x) && (0..height).contains(&other_y) {
+                        distance += 1;
+                        if rows[other_y as usize][other_x as usize] >= tree {
+                            blocked = true;
+                            break;
+                        }
+                        other_x += dx;
+                        other_y += dy;
+                    }
+
+                    seen_from_edge |= !blocked;
+                    score *= distance;
+                }
+
+                visible += seen_from_edge as u32;
+                best_score = best_score.max(score);
+            }
+        }
+
+        [visible.into(), best_score.into()]
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day8>(100, 30, naive_answers);
+    }
+
+    #[test]
+    fn nominal_stack_agrees() {
+        common::check_alternatives::<Day8>(100, 30);
+    }
+}
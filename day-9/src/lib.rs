@@ -0,0 +1,551 @@
+//! --- Day 9: Rope Bridge ---
+
+use common::geom::{Dir4, Point2};
+use common::parse::{lines, number, words};
+use common::viz::{Cell, Colour, Frame, Render};
+use common::{Answer, Config, ConfigError, Grid, Implementation, ParseError, Rng, Solution};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+pub struct Day9;
+
+/// Knots behind the head that a point of the area can tell apart, one bit each.
+const MAX_TAIL_KNOTS: usize = u64::BITS as usize;
+
+/// Tunable constants, set in `config.toml` or with `--set`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Constants {
+    /// Knots of the rope of part one, head and tail included
+    knots_part_one: usize,
+    /// Knots of the rope of part two, head and tail included
+    knots_part_two: usize,
+}
+
+impl Default for Constants {
+    fn default() -> Self {
+        Constants {
+            knots_part_one: 2,
+            knots_part_two: 10,
+        }
+    }
+}
+
+#[derive(Debug, Copy)]
+pub struct Motion {
+    direction: Dir4,
+    steps: usize,
+}
+
+impl Clone for Motion {
+    fn clone(&self) -> Motion {
+        *self
+    }
+}
+
+#[derive(Debug)]
+pub struct Motions {
+    series: Vec<Motion>,
+    constants: Constants,
+}
+
+#[derive(Debug, Copy, Default)]
+struct Point {
+    start: bool,
+    head: bool,
+    /// Bit `i` set when the knot `i` behind the head is here
+    rope: u64,
+    tail_visited: bool,
+}
+
+impl Clone for Point {
+    fn clone(&self) -> Point {
+        *self
+    }
+}
+
+#[derive(Default)]
+struct Area {
+    grid: Grid<Point>,
+    head_loc: (usize, usize),      // (x, y)
+    rope_loc: Vec<(usize, usize)>, // (x, y)
+}
+
+enum Rope {
+    Head,
+    None,
+}
+
+fn parse_input_file(file_content: &str) -> Result<Vec<Motion>, ParseError> {
+    let lines = lines(file_content);
+    let mut motion_series: Vec<Motion> = Vec::new();
+
+    for line in lines {
+        let [direction, steps] = words::<2>(file_content, line)?;
+        let direction = match direction.chars().collect::<Vec<char>>()[..] {
+            [char @ ('L' | 'D' | 'R' | 'U')] => Dir4::from_char(char),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::at(file_content, direction, "a direction (L, D, R or U)"))?;
+
+        motion_series.push(Motion {
+            direction,
+            steps: number::<usize>(file_content, steps)?,
+        });
+    }
+
+    Ok(motion_series)
+}
+
+fn generate_motions(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['L', 'D', 'R', 'U']),
+                rng.range(1..=19)
+            )
+        })
+        .collect()
+}
+
+fn find(area: &Area, rope_knot: Rope, knot_index: usize) -> (usize, usize) {
+    area.grid
+        .positions()
+        .find(|&position| {
+            let point = area.grid[position];
+
+            if knot_index < MAX_TAIL_KNOTS {
+                point.rope >> knot_index & 1 == 1
+            } else {
+                match rope_knot {
+                    Rope::Head => point.head,
+                    Rope::None => false,
+                }
+            }
+        })
+        .unwrap_or((0, 0))
+}
+
+fn motion_is_possible(area: &Area, motion: &Motion) -> (bool, Motion) {
+    let max_y = area.grid.height();
+    let max_x = area.grid.width();
+    let steps = motion.steps;
+    let mut motion_possible = true;
+    let mut delta_motion = *motion;
+    let (x, y) = area.head_loc;
+
+    match motion.direction {
+        Dir4::Left => {
+            if x < steps {
+                motion_possible = false;
+                delta_motion.steps -= x;
+            }
+        }
+        Dir4::Down => {
+            if (y + steps) >= max_y {
+                motion_possible = false;
+                delta_motion.steps = steps - (max_y - y - 1);
+            }
+        }
+        Dir4::Right => {
+            if (x + steps) >= max_x {
+                motion_possible = false;
+                delta_motion.steps = steps - (max_x - x - 1);
+            }
+        }
+        Dir4::Up => {
+            if y < steps {
+                motion_possible = false;
+                delta_motion.steps -= y;
+            }
+        }
+    }
+
+    (motion_possible, delta_motion)
+}
+
+fn extend_area(area: &mut Area, motion: &Motion) {
+    let steps = motion.steps;
+    let point = Point::default();
+
+    match motion.direction {
+        Dir4::Left => area.grid.expand(steps, 0, 0, 0, point),
+        Dir4::Down => area.grid.expand(0, 0, 0, steps, point),
+        Dir4::Right => area.grid.expand(0, 0, steps, 0, point),
+        Dir4::Up => area.grid.expand(0, steps, 0, 0, point),
+    }
+
+    // Update Head and Tail locations
+    area.head_loc = find(area, Rope::Head, MAX_TAIL_KNOTS);
+
+    // Update whole Rope's Knots locations
+    for i in 0..area.rope_loc.len() {
+        area.rope_loc[i] = find(area, Rope::None, i);
+    }
+}
+
+fn move_knot(head_knot: (usize, usize), tail_knot: (usize, usize)) -> (usize, usize) {
+    let tail = Point2::from(tail_knot);
+    let delta = Point2::from(head_knot) - tail;
+
+    // Knots still touching, diagonals included, do not move
+    if delta.chebyshev_len() <= 1 {
+        return tail_knot;
+    }
+
+    (tail + delta.signum())
+        .to_position()
+        .expect("Knot should stay inside the area")
+}
+
+fn perform_motion(area: &mut Area, motion: &Motion, knots: u32) {
+    let mut head_knot_loc = area.head_loc;
+    let tail_knots = knots as usize - 1;
+
+    for _ in 0..motion.steps {
+        // Move [H]ead
+        area.grid[head_knot_loc].head = false;
+        head_knot_loc = (Point2::from(head_knot_loc) + motion.direction.vector())
+            .to_position()
+            .expect("Head should stay inside the area");
+        area.grid[head_knot_loc].head = true;
+        area.head_loc = head_knot_loc;
+
+        let mut prev_knot_loc = head_knot_loc;
+
+        for i in 0..tail_knots {
+            let mut current_knot_loc = area.rope_loc[i];
+
+            // Attempt to move [T]ail/Knot
+            area.grid[current_knot_loc].rope &= !(1 << i);
+            current_knot_loc = move_knot(prev_knot_loc, current_knot_loc);
+            area.grid[current_knot_loc].rope |= 1 << i;
+
+            // Update knot location
+            area.rope_loc[i] = current_knot_loc;
+
+            prev_knot_loc = current_knot_loc;
+
+            // Check if this knot is Tail
+            if i == (tail_knots - 1) {
+                // Set tail_visited to true at new tail location
+                area.grid[current_knot_loc].tail_visited = true;
+            }
+        }
+    }
+}
+
+fn start_area(area: &mut Area, knots: u32) {
+    // Initialize the first Point
+    area.grid = Grid::new(
+        1,
+        1,
+        Point {
+            start: true,
+            head: true,
+            rope: u64::MAX,
+            tail_visited: true,
+        },
+    );
+    area.rope_loc = vec![(0, 0); knots as usize - 1];
+}
+
+fn apply_motion(area: &mut Area, motion: &Motion, knots: u32) {
+    let (motion_possible, delta_motion) = motion_is_possible(area, motion);
+    if !motion_possible {
+        extend_area(area, &delta_motion);
+    }
+    perform_motion(area, motion, knots);
+}
+
+fn perform_motions(mut area: Area, motion_series: &Vec<Motion>, knots: u32) -> Area {
+    start_area(&mut area, knots);
+
+    for motion in motion_series {
+        apply_motion(&mut area, motion, knots);
+    }
+
+    area
+}
+
+fn count_visited_cells(area: &Area) -> u32 {
+    area.grid.iter().filter(|point| point.tail_visited).count() as u32
+}
+
+// The knots on an unbounded plane, only remembering the positions the tail visited
+fn count_visited_set(motion_series: &[Motion], knots: usize) -> Answer {
+    let mut rope = vec![Point2::default(); knots];
+    let mut visited = HashSet::from([Point2::default()]);
+
+    for motion in motion_series {
+        for _ in 0..motion.steps {
+            rope[0] += motion.direction.vector();
+
+            for i in 1..knots {
+                let delta = rope[i - 1] - rope[i];
+                if delta.chebyshev_len() > 1 {
+                    rope[i] += delta.signum();
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len().into()
+}
+
+fn area_cell(point: &Point, knots: u32) -> Cell {
+    let tail_knots = knots as usize - 1;
+
+    if point.head {
+        return Cell::new('H', Colour::Red);
+    }
+
+    // Knots closer to the head cover the ones behind them
+    if let Some(i) = (0..tail_knots).find(|&i| point.rope >> i & 1 == 1) {
+        // Knots after the 9th get letters, then all look alike
+        let symbol = match tail_knots {
+            1 => 'T',
+            _ => char::from_digit(i as u32 + 1, 36).unwrap_or('*'),
+        };
+        return Cell::new(symbol, Colour::Yellow);
+    }
+
+    if point.start {
+        Cell::new('s', Colour::Cyan)
+    } else if point.tail_visited {
+        Cell::new('#', Colour::Grey)
+    } else {
+        Cell::plain('.')
+    }
+}
+
+/// The rope following its motions, one step per frame.
+struct RopeRender<'a> {
+    area: Area,
+    motion_series: &'a [Motion],
+    knots: u32,
+    motion_index: usize,
+    step_index: usize,
+}
+
+impl Render for RopeRender<'_> {
+    fn next_frame(&mut self) -> Option<Frame> {
+        // The first frame is the rope at its start
+        if self.area.grid.width() == 0 {
+            start_area(&mut self.area, self.knots);
+        } else {
+            let motion = self.motion_series.get(self.motion_index)?;
+
+            apply_motion(
+                &mut self.area,
+                &Motion {
+                    direction: motion.direction,
+                    steps: 1,
+                },
+                self.knots,
+            );
+
+            self.step_index += 1;
+            if self.step_index == motion.steps {
+                self.motion_index += 1;
+                self.step_index = 0;
+            }
+        }
+
+        Some(Frame {
+            cells: self.area.grid.map(|point| area_cell(point, self.knots)),
+            caption: format!(
+                "Motion {}/{}  Tail visited {}",
+                self.motion_index,
+                self.motion_series.len(),
+                count_visited_cells(&self.area)
+            ),
+            focus: Some(self.area.head_loc),
+        })
+    }
+}
+
+impl Solution for Day9 {
+    type Input = Motions;
+
+    const DAY: u32 = 9;
+    const NAME: &'static str = "--- Day 9: Rope Bridge ---";
+    const QUESTIONS: [&'static str; 2] = [
+        "Simulate your complete hypothetical series of motions. \
+        How many positions does the tail of the rope visit at least once?",
+        "Simulate your complete series of motions on a larger rope with ten knots. \
+        How many positions does the tail of the rope visit at least once?",
+    ];
+
+    fn parse(file_content: &str) -> Result<Self::Input, ParseError> {
+        Ok(Motions {
+            series: parse_input_file(file_content)?,
+            constants: Constants::default(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut area = Area::default();
+
+        area = perform_motions(area, &input.series, input.constants.knots_part_one as u32);
+
+        count_visited_cells(&area).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut area = Area::default();
+
+        area = perform_motions(area, &input.series, input.constants.knots_part_two as u32);
+
+        count_visited_cells(&area).into()
+    }
+
+    fn configure(input: &mut Self::Input, config: &Config) -> Result<(), ConfigError> {
+        let constants: Constants = config.to_typed()?;
+        let knots = 2..=MAX_TAIL_KNOTS + 1;
+        if !knots.contains(&constants.knots_part_one) || !knots.contains(&constants.knots_part_two)
+        {
+            return Err(ConfigError::new(format!(
+                "Ropes should have 2 to {} knots",
+                MAX_TAIL_KNOTS + 1
+            )));
+        }
+
+        input.constants = constants;
+        Ok(())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_motions(size, rng))
+    }
+
+    fn alternatives() -> Vec<Implementation<Self::Input>> {
+        vec![
+            Implementation {
+                name: "set",
+                part: 1,
+                solve: |input| count_visited_set(&input.series, input.constants.knots_part_one),
+            },
+            Implementation {
+                name: "set",
+                part: 2,
+                solve: |input| count_visited_set(&input.series, input.constants.knots_part_two),
+            },
+        ]
+    }
+
+    fn visualize(input: &Self::Input, part: usize) -> Option<Box<dyn Render + '_>> {
+        Some(Box::new(RopeRender {
+            area: Area::default(),
+            motion_series: &input.series,
+            knots: if part == 1 {
+                input.constants.knots_part_one
+            } else {
+                input.constants.knots_part_two
+            } as u32,
+            motion_index: 0,
+            step_index: 0,
+        }))
+    }
+}
+
+// ================ Unit Tests ================
+
+common::example_tests!(Day9, example_1 => 1, example_2 => 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Positions visited by each knot of a `knots` long rope, the head first
+    fn naive_visits(file_content: &str, knots: usize) -> Vec<HashSet<(i32, i32)>> {
+        let mut rope: Vec<(i32, i32)> = vec![(0, 0); knots];
+        let mut visited = vec![HashSet::from([(0, 0)]); knots];
+
+        for line in file_content.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            let (dx, dy) = match direction {
+                "L" => (-1, 0),
+                "R" => (1, 0),
+                "U" => (0, -1),
+                _ => (0, 1),
+            };
+
+            for _ in 0..steps.parse::<usize>().unwrap() {
+                rope[0].0 += dx;
+                rope[0].1 += dy;
+
+                for i in 1..knots {
+                    let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                    if x.abs() > 1 || y.abs() > 1 {
+                        rope[i].0 += x.signum();
+                        rope[i].1 += y.signum();
+                    }
+                }
+                for (knot, visited) in rope.iter().zip(&mut visited) {
+                    visited.insert(*knot);
+                }
+            }
+        }
+
+        visited
+    }
+
+    #[test]
+    fn nominal_naive_reference() {
+        common::check_naive::<Day9>(50, 40, |file_content| {
+            [
+                naive_visits(file_content, 2)[1].len().into(),
+                naive_visits(file_content, 10)[9].len().into(),
+            ]
+        });
+    }
+
+    #[test]
+    fn nominal_set_agrees() {
+        common::check_alternatives::<Day9>(50, 40);
+    }
+
+    #[test]
+    fn nominal_configure() {
+        let file_content = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n";
+        let mut input = Day9::parse(file_content).unwrap();
+        let mut config = Config::default();
+        config.set("knots_part_one=10").unwrap();
+        config.set("knots_part_two=30").unwrap();
+        Day9::configure(&mut input, &config).unwrap();
+
+        assert_eq!(Day9::part_one(&input), Answer::from(36));
+        assert_eq!(
+            Day9::part_two(&input),
+            naive_visits(file_content, 30)[29].len().into()
+        );
+        for alternative in Day9::alternatives() {
+            let expected = if alternative.part == 1 {
+                Day9::part_one(&input)
+            } else {
+                Day9::part_two(&input)
+            };
+            assert_eq!((alternative.solve)(&input), expected);
+        }
+
+        config.set("knots_part_two=1").unwrap();
+        assert!(Day9::configure(&mut input, &config).is_err());
+    }
+
+    #[test]
+    fn nominal_tail_visits_at_most_head_visits() {
+        common::check_generated::<Day9>(50, 40, |file_content, input| {
+            // Behind a single knot, the tail only ever steps where the head just was
+            let visited = naive_visits(file_content, 2);
+            assert!(visited[1].is_subset(&visited[0]));
+
+            let Answer::Integer(tail_visits) = Day9::part_one(input) else {
+                panic!("Part one should be a number");
+            };
+            assert!(tail_visits as usize <= visited[0].len());
+        });
+    }
+}